@@ -0,0 +1,49 @@
+//! Detects the compiler version to enable features that require it.
+//!
+//! Everything provided by this crate still works on older compilers, albeit
+//! with less helpful error messages.
+
+use std::env;
+use std::process::Command;
+use std::str;
+
+fn main() {
+    let minor = match rustc_minor_version() {
+        Some(minor) => minor,
+        None => return,
+    };
+
+    if minor >= 80 {
+        println!("cargo:rustc-check-cfg=cfg(const_msg)");
+        println!("cargo:rustc-check-cfg=cfg(offset_of)");
+        println!("cargo:rustc-check-cfg=cfg(core_unwind_safe)");
+    }
+
+    // `UnwindSafe` is only available in `core` since Rust 1.56.
+    if minor >= 56 {
+        println!("cargo:rustc-cfg=core_unwind_safe");
+    }
+
+    // Custom failure messages require `panic!` and building a `&str` from a
+    // byte buffer in constant evaluation.
+    if minor >= 64 {
+        println!("cargo:rustc-cfg=const_msg");
+    }
+
+    // Field offsets require `offset_of!` with support for nested fields.
+    if minor >= 82 {
+        println!("cargo:rustc-cfg=offset_of");
+    }
+}
+
+fn rustc_minor_version() -> Option<u32> {
+    let rustc = env::var_os("RUSTC")?;
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    let version = str::from_utf8(&output.stdout).ok()?;
+
+    let mut pieces = version.split('.');
+    if pieces.next() != Some("rustc 1") {
+        return None;
+    }
+    pieces.next()?.parse().ok()
+}
@@ -0,0 +1,283 @@
+use proc_macro2::{Span, TokenStream};
+use repr::{self, Hint};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    BinOp, Data, DeriveInput, Error, Expr, Fields, GenericParam, Ident, Index,
+    Path, Result,
+};
+
+/// A single comma-separated assertion within `#[assert(...)]`.
+pub enum Clause {
+    /// A layout comparison, such as `size == 4` or `align <= 8`.
+    Layout {
+        property: Property,
+        op: BinOp,
+        value: Expr,
+    },
+    /// A trait check, such as `impl(Send, Sync)` or `not_impl(Copy)`.
+    Impl {
+        kind: ImplKind,
+        traits: Punctuated<Path, Token![,]>,
+    },
+    /// A check that a struct has no padding bytes, via `no_padding`.
+    NoPadding(Span),
+    /// A check of the item's own `#[repr(...)]` attributes, such as
+    /// `repr(C)`.
+    Repr { span: Span, hints: Vec<Hint> },
+}
+
+/// How the traits of an `Impl` clause must be implemented, along with the
+/// span of the keyword.
+pub enum ImplKind {
+    All(Span),
+    NotAny(Span),
+    One(Span),
+}
+
+/// A layout property of a type that can be compared against a `usize`.
+pub enum Property {
+    Size(Ident),
+    Align(Ident),
+}
+
+impl Parse for Clause {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![impl]) {
+            let token: Token![impl] = input.parse()?;
+            return parse_impl(input, ImplKind::All(token.span));
+        }
+
+        let ident: Ident = input.parse()?;
+        let property = match ident.to_string().as_str() {
+            "size" => Property::Size(ident),
+            "align" => Property::Align(ident),
+            "not_impl" => {
+                return parse_impl(input, ImplKind::NotAny(ident.span()))
+            }
+            "impl_one" => {
+                return parse_impl(input, ImplKind::One(ident.span()))
+            }
+            "no_padding" => return Ok(Clause::NoPadding(ident.span())),
+            "repr" => {
+                let hints = repr::parse(input)?;
+                return Ok(Clause::Repr {
+                    span: ident.span(),
+                    hints,
+                });
+            }
+            _ => return Err(Error::new(
+                ident.span(),
+                "expected `size`, `align`, `impl`, `not_impl`, `impl_one`, \
+                 `no_padding`, or `repr`",
+            )),
+        };
+
+        let op: BinOp = input.parse()?;
+        match op {
+            BinOp::Eq(_)
+            | BinOp::Ne(_)
+            | BinOp::Lt(_)
+            | BinOp::Le(_)
+            | BinOp::Gt(_)
+            | BinOp::Ge(_) => {}
+            _ => {
+                return Err(Error::new(
+                    op.span(),
+                    "expected one of `==`, `!=`, `<`, `<=`, `>`, or `>=`",
+                ))
+            }
+        }
+
+        let value = input.parse()?;
+        Ok(Clause::Layout {
+            property,
+            op,
+            value,
+        })
+    }
+}
+
+/// Parses the parenthesized traits following an `impl`-like keyword.
+fn parse_impl(input: ParseStream, kind: ImplKind) -> Result<Clause> {
+    let content;
+    let paren = parenthesized!(content in input);
+    let traits = content.parse_terminated(Path::parse)?;
+    if traits.is_empty() {
+        return Err(Error::new(paren.span, "expected at least one trait"));
+    }
+    Ok(Clause::Impl { kind, traits })
+}
+
+impl Clause {
+    /// Generates the checks for this clause against `input`.
+    pub fn expand(&self, input: &DeriveInput) -> TokenStream {
+        match self {
+            Clause::Layout {
+                property,
+                op,
+                value,
+            } => expand_layout(input, property, op, value)
+                .unwrap_or_else(|error| error.to_compile_error()),
+            Clause::Impl { kind, traits } => expand_impl(input, kind, traits),
+            Clause::NoPadding(span) => expand_no_padding(input, *span)
+                .unwrap_or_else(|error| error.to_compile_error()),
+            Clause::Repr { span, hints } => repr::expand(input, *span, hints)
+                .unwrap_or_else(|error| error.to_compile_error()),
+        }
+    }
+}
+
+fn expand_impl(
+    input: &DeriveInput,
+    kind: &ImplKind,
+    traits: &Punctuated<Path, Token![,]>,
+) -> TokenStream {
+    let (span, name) = match *kind {
+        ImplKind::All(span) => (span, quote!(assert_impl_all)),
+        ImplKind::NotAny(span) => (span, quote!(assert_impl_not_any)),
+        ImplKind::One(span) => (span, quote!(assert_impl_one)),
+    };
+    let mac = quote_spanned!(span=> ::static_assertions::#name!);
+
+    let ident = &input.ident;
+    if input.generics.params.is_empty() {
+        return quote_spanned! {span=>
+            #mac(#ident: #traits);
+        };
+    }
+
+    // Generic types are checked for all parameters allowed by the item's own
+    // bounds. Defaults are not allowed on the generated function's parameters.
+    let mut generics = input.generics.clone();
+    for param in &mut generics.params {
+        match param {
+            GenericParam::Type(param) => {
+                param.attrs.clear();
+                param.eq_token = None;
+                param.default = None;
+            }
+            GenericParam::Lifetime(param) => param.attrs.clear(),
+            GenericParam::Const(param) => {
+                param.attrs.clear();
+                param.eq_token = None;
+                param.default = None;
+            }
+        }
+    }
+    let params = &generics.params;
+    let predicates = generics.where_clause.as_ref().map(|w| &w.predicates);
+    let (_, ty_generics, _) = generics.split_for_impl();
+
+    quote_spanned! {span=>
+        #mac(for(#params) where(#predicates) #ident #ty_generics: #traits);
+    }
+}
+
+fn expand_no_padding(input: &DeriveInput, span: Span) -> Result<TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "padding assertions are not supported for generic types",
+        ));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new(
+                span,
+                "padding assertions are only supported for structs",
+            ))
+        }
+    };
+    let fields: Vec<TokenStream> = match fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|field| {
+                let ident = &field.ident;
+                quote!(#ident)
+            })
+            .collect(),
+        Fields::Unnamed(fields) => (0..fields.unnamed.len())
+            .map(|index| {
+                let index = Index::from(index);
+                quote!(#index)
+            })
+            .collect(),
+        // Types without fields are zero-sized and cannot have padding.
+        Fields::Unit => return Ok(TokenStream::new()),
+    };
+    if fields.is_empty() {
+        return Ok(TokenStream::new());
+    }
+
+    let ident = &input.ident;
+    Ok(quote_spanned! {span=>
+        ::static_assertions::assert_no_padding!(#ident: #(#fields),*);
+    })
+}
+
+fn expand_layout(
+    input: &DeriveInput,
+    property: &Property,
+    op: &BinOp,
+    value: &Expr,
+) -> Result<TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "layout assertions are not supported for generic types",
+        ));
+    }
+
+    let ty = &input.ident;
+    let (span, function) = match property {
+        Property::Size(ident) => (ident.span(), quote!(size_of)),
+        Property::Align(ident) => (ident.span(), quote!(align_of)),
+    };
+
+    // Generated tokens are given the span of the property keyword so that
+    // errors point at the offending clause rather than the whole attribute.
+    let actual = quote_spanned!(span=> core::mem::#function::<#ty>());
+    let expected = quote_spanned!(span=> (#value));
+
+    // Where possible, failures are reported as arithmetic overflow or an array
+    // length mismatch so that the compiler shows the actual value.
+    let check = match op {
+        BinOp::Eq(_) => quote_spanned! {span=>
+            const _: [(); #expected] = [(); #actual];
+        },
+        BinOp::Lt(_) => quote_spanned! {span=>
+            const _: usize = #expected - (#actual + 1);
+        },
+        BinOp::Le(_) => quote_spanned! {span=>
+            const _: usize = #expected - #actual;
+        },
+        BinOp::Gt(_) => quote_spanned! {span=>
+            const _: usize = #actual - (#expected + 1);
+        },
+        BinOp::Ge(_) => quote_spanned! {span=>
+            const _: usize = #actual - #expected;
+        },
+        _ => quote_spanned! {span=>
+            const _: [(); 1] = [(); (#actual #op #expected) as usize];
+        },
+    };
+
+    Ok(wrap(span, check))
+}
+
+/// Places `checks` in an anonymous constant that has access to `core`,
+/// regardless of the caller's edition or whether it is `#![no_std]`.
+fn wrap(span: Span, checks: TokenStream) -> TokenStream {
+    quote_spanned! {span=>
+        #[allow(unknown_lints, unused_parens, clippy::all)]
+        const _: () = {
+            extern crate core;
+            #checks
+        };
+    }
+}
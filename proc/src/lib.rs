@@ -80,13 +80,216 @@
 #![deny(missing_docs)]
 
 extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+#[macro_use]
+extern crate syn;
+
 use proc_macro::TokenStream;
+use syn::{punctuated::Punctuated, DeriveInput};
+
+mod clause;
+mod repr;
+
+use clause::Clause;
 
 /// Statically assert aspects of types, traits, and more.
 ///
-/// This currently does nothing. Create an issue if you have ideas for what this
-/// could do!
+/// The annotated item is kept as-is and each comma-separated clause generates
+/// a check against it.
+///
+/// # Layout
+///
+/// The `size` and `align` of a type can be compared against a constant
+/// [`usize`] expression via `==`, `!=`, `<`, `<=`, `>`, or `>=`. These checks
+/// are only supported for types without generic parameters.
+///
+/// ```
+/// # #[macro_use] extern crate proc_static_assertions; fn main() {}
+/// #[assert(size == 8, align == 4)]
+/// struct Pair {
+///     a: i32,
+///     b: i32,
+/// }
+///
+/// #[assert(size <= 16, size != 0, align >= 2)]
+/// enum Value {
+///     Int(i64),
+///     Bool(bool),
+/// }
+/// ```
+///
+/// The following example fails to compile because the fields of `Pair` take
+/// up 8 bytes:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate proc_static_assertions; fn main() {}
+/// #[assert(size == 4)]
+/// struct Pair {
+///     a: i32,
+///     b: i32,
+/// }
+/// ```
+///
+/// # Traits
+///
+/// Trait implementations can be checked via `impl(...)`, `not_impl(...)`, and
+/// `impl_one(...)` clauses, which respectively expand to [`assert_impl_all!`],
+/// [`assert_impl_not_any!`], and [`assert_impl_one!`]. These require
+/// [`static_assertions`] to be a dependency of the crate.
+///
+/// ```
+/// # #[macro_use] extern crate proc_static_assertions;
+/// # extern crate static_assertions; fn main() {}
+/// use std::rc::Rc;
+///
+/// #[assert(impl(Send, Sync), not_impl(Copy))]
+/// struct Buffer {
+///     bytes: Vec<u8>,
+/// }
+///
+/// #[assert(impl(Clone), not_impl(Send, Sync), impl_one(Into<Rc<str>>, Copy))]
+/// #[derive(Clone)]
+/// struct Name(Rc<str>);
+///
+/// impl From<Name> for Rc<str> {
+///     fn from(name: Name) -> Self {
+///         name.0
+///     }
+/// }
+/// ```
+///
+/// For generic types, the traits must be implemented for _all_ parameters
+/// allowed by the type's bounds and `where` clause. The traits themselves
+/// cannot refer to the type's parameters.
+///
+/// ```
+/// # #[macro_use] extern crate proc_static_assertions;
+/// # extern crate static_assertions; fn main() {}
+/// #[assert(impl(Send, Sync))]
+/// struct Wrapper<T: Send + Sync>(Vec<T>);
+///
+/// #[assert(impl(Send), not_impl(Sync))]
+/// struct Shared<T>(std::cell::Cell<T>)
+/// where
+///     T: Send;
+/// ```
+///
+/// The following example fails to compile because `Vec<T>` is not [`Sync`]
+/// when `T` is only [`Send`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate proc_static_assertions;
+/// # extern crate static_assertions; fn main() {}
+/// #[assert(impl(Send, Sync))]
+/// struct Wrapper<T: Send>(Vec<T>);
+/// ```
+///
+/// In turn, `not_impl(...)` only requires each trait to not be implemented for
+/// _some_ parameters, and `impl_one(...)` requires exactly one trait to be
+/// implemented for all parameters. This means that `not_impl(Send)` holds for
+/// the following type, even though `Boxed<u8>` is [`Send`]:
+///
+/// ```
+/// # #[macro_use] extern crate proc_static_assertions;
+/// # extern crate static_assertions; fn main() {}
+/// #[assert(not_impl(Send))]
+/// struct Boxed<T>(T);
+/// ```
+///
+/// # Padding
+///
+/// A `no_padding` clause asserts that a struct has no padding bytes, by
+/// passing all of its fields to [`assert_no_padding!`]. This requires
+/// [`static_assertions`] to be a dependency of the crate and Rust 1.64 or
+/// later, and is only supported for structs without generic parameters.
+///
+/// ```
+/// # #[macro_use] extern crate proc_static_assertions;
+/// # extern crate static_assertions; fn main() {}
+/// #[assert(no_padding)]
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     version: u16,
+///     flags: u16,
+///     len: u64,
+/// }
+/// ```
+///
+/// The following example fails to compile because 4 bytes of padding are
+/// needed to align `len`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate proc_static_assertions;
+/// # extern crate static_assertions; fn main() {}
+/// #[assert(no_padding)]
+/// #[repr(C)]
+/// struct Packet {
+///     kind: u32,
+///     len: u64,
+/// }
+/// ```
+///
+/// # Representation
+///
+/// A `repr(...)` clause asserts that the item has each of the given
+/// [representation] hints, such as `C`, `transparent`, `u8`, `align(8)`, or
+/// `packed`, and that it has none that conflict with them. This keeps types
+/// relied on by [FFI] from silently losing their layout guarantees. The
+/// `#[repr]` attributes may be placed before or after `#[assert]`.
+///
+/// ```
+/// # #[macro_use] extern crate proc_static_assertions; fn main() {}
+/// #[assert(repr(C))]
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     len: u32,
+/// }
+///
+/// #[repr(u8)]
+/// #[assert(repr(u8))]
+/// enum Kind {
+///     File,
+///     Directory,
+/// }
+/// ```
+///
+/// No layout checks are generated for `repr(transparent)`, since the compiler
+/// already guarantees that such a type has the same layout as its field.
+///
+/// The following example fails to compile because `Handle` is missing
+/// `#[repr(transparent)]` and has the conflicting `#[repr(C)]` instead:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate proc_static_assertions; fn main() {}
+/// #[assert(repr(transparent))]
+/// #[repr(C)]
+/// struct Handle(u32);
+/// ```
+///
+/// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+/// [`Sync`]: https://doc.rust-lang.org/std/marker/trait.Sync.html
+/// [`assert_impl_all!`]: https://docs.rs/static_assertions/1.1.0/static_assertions/macro.assert_impl_all.html
+/// [`assert_impl_not_any!`]: https://docs.rs/static_assertions/1.1.0/static_assertions/macro.assert_impl_not_any.html
+/// [`assert_impl_one!`]: https://docs.rs/static_assertions/1.1.0/static_assertions/macro.assert_impl_one.html
+/// [`assert_no_padding!`]: https://docs.rs/static_assertions/1.1.0/static_assertions/macro.assert_no_padding.html
+/// [`static_assertions`]: https://github.com/nvzqz/static-assertions-rs
+/// [representation]: https://doc.rust-lang.org/reference/type-layout.html#representations
+/// [FFI]: https://en.wikipedia.org/wiki/Foreign_function_interface
 #[proc_macro_attribute]
-pub fn assert(_attr: TokenStream, _item: TokenStream) -> TokenStream {
-    TokenStream::new()
+pub fn assert(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let clauses = parse_macro_input!(
+        attr with Punctuated::<Clause, Token![,]>::parse_terminated
+    );
+    let input = parse_macro_input!(item as DeriveInput);
+    let checks = clauses.iter().map(|clause| clause.expand(&input));
+
+    let expanded = quote! {
+        #input
+        #(#checks)*
+    };
+    expanded.into()
 }
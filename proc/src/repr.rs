@@ -0,0 +1,163 @@
+use proc_macro2::{Span, TokenStream};
+use std::fmt;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Attribute, DeriveInput, Error, Lit, Meta, NestedMeta, Result,
+};
+
+/// A representation hint, as given to `#[repr(...)]`.
+#[derive(PartialEq)]
+pub enum Hint {
+    Rust,
+    C,
+    Transparent,
+    /// A primitive integer representation, such as `u8`.
+    Int(String),
+    Align(u64),
+    /// Packing to the given alignment, where `packed` is the same as
+    /// `packed(1)`.
+    Packed(u64),
+}
+
+const INTS: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64",
+    "i128", "isize",
+];
+
+impl Hint {
+    fn from_meta(meta: &NestedMeta) -> Result<Self> {
+        let meta = match meta {
+            NestedMeta::Meta(meta) => meta,
+            NestedMeta::Lit(lit) => {
+                return Err(Error::new_spanned(lit, "expected a `repr` hint"))
+            }
+        };
+        let ident = match meta.path().get_ident() {
+            Some(ident) => ident.to_string(),
+            None => {
+                return Err(Error::new_spanned(meta, "expected a `repr` hint"))
+            }
+        };
+
+        match (ident.as_str(), meta) {
+            ("Rust", Meta::Path(_)) => Ok(Hint::Rust),
+            ("C", Meta::Path(_)) => Ok(Hint::C),
+            ("transparent", Meta::Path(_)) => Ok(Hint::Transparent),
+            ("packed", Meta::Path(_)) => Ok(Hint::Packed(1)),
+            (int, Meta::Path(_)) if INTS.contains(&int) => Ok(Hint::Int(ident)),
+            ("align", Meta::List(list)) => parse_int(list).map(Hint::Align),
+            ("packed", Meta::List(list)) => parse_int(list).map(Hint::Packed),
+            _ => Err(Error::new_spanned(meta, "unsupported `repr` hint")),
+        }
+    }
+
+    /// Returns whether `self` and `other` cannot both be present on a type.
+    fn conflicts_with(&self, other: &Hint) -> bool {
+        use self::Hint::*;
+
+        match (self, other) {
+            _ if self == other => false,
+            (Transparent, _) | (_, Transparent) => true,
+            (Rust, C) | (C, Rust) => true,
+            (Int(_), Int(_)) => true,
+            (Align(_), Align(_)) | (Packed(_), Packed(_)) => true,
+            (Align(_), Packed(_)) | (Packed(_), Align(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Hint::Rust => f.write_str("Rust"),
+            Hint::C => f.write_str("C"),
+            Hint::Transparent => f.write_str("transparent"),
+            Hint::Int(int) => f.write_str(int),
+            Hint::Align(n) => write!(f, "align({})", n),
+            Hint::Packed(1) => f.write_str("packed"),
+            Hint::Packed(n) => write!(f, "packed({})", n),
+        }
+    }
+}
+
+fn parse_int(list: &syn::MetaList) -> Result<u64> {
+    match list.nested.iter().collect::<Vec<_>>().as_slice() {
+        [NestedMeta::Lit(Lit::Int(int))] => int.base10_parse(),
+        _ => Err(Error::new_spanned(list, "expected a single integer")),
+    }
+}
+
+/// Parses the parenthesized hints following `repr`.
+pub fn parse(input: ParseStream) -> Result<Vec<Hint>> {
+    let content;
+    let paren = parenthesized!(content in input);
+    let hints: Punctuated<NestedMeta, Token![,]> =
+        content.parse_terminated(NestedMeta::parse)?;
+    if hints.is_empty() {
+        return Err(Error::new(
+            paren.span,
+            "expected at least one `repr` hint",
+        ));
+    }
+    hints.iter().map(Hint::from_meta).collect()
+}
+
+/// Collects the hints of all `#[repr(...)]` attributes.
+fn hints_of(attrs: &[Attribute]) -> Result<Vec<Hint>> {
+    let mut hints = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("repr")) {
+        if let Meta::List(list) = attr.parse_meta()? {
+            for meta in &list.nested {
+                hints.push(Hint::from_meta(meta)?);
+            }
+        }
+    }
+    Ok(hints)
+}
+
+/// Checks that each of `required` is present on `input` and that no present
+/// hint conflicts with it. This needs no generated code since the attributes
+/// are known here.
+pub fn expand(
+    input: &DeriveInput,
+    span: Span,
+    required: &[Hint],
+) -> Result<TokenStream> {
+    let present = hints_of(&input.attrs)?;
+    let ident = &input.ident;
+    let mut errors = Vec::new();
+
+    for hint in required {
+        let conflicts: Vec<&Hint> = present
+            .iter()
+            .filter(|other| hint.conflicts_with(other))
+            .collect();
+        for other in &conflicts {
+            errors.push(Error::new(
+                span,
+                format!(
+                    "expected `{}` to have `#[repr({})]`, but it has the \
+                     conflicting `#[repr({})]`",
+                    ident, hint, other
+                ),
+            ));
+        }
+        if conflicts.is_empty() && !present.contains(hint) {
+            errors.push(Error::new(
+                span,
+                format!("expected `{}` to have `#[repr({})]`", ident, hint),
+            ));
+        }
+    }
+
+    let mut errors = errors.into_iter();
+    match errors.next() {
+        Some(mut error) => {
+            errors.for_each(|other| error.combine(other));
+            Err(error)
+        }
+        None => Ok(TokenStream::new()),
+    }
+}
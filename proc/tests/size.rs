@@ -1,3 +1,5 @@
+#![allow(dead_code)]
+
 #[macro_use]
 extern crate proc_static_assertions;
 
@@ -5,3 +7,21 @@ extern crate proc_static_assertions;
 struct Foo {
     value: i32,
 }
+
+#[assert(size == 2 * 4, size != 0, size < 9, size <= 8, size > 7, size >= 8)]
+#[assert(align == 4, align != 8, align < 8, align <= 4, align > 2, align >= 4)]
+struct Pair(i32, i32);
+
+const BYTES: usize = 32;
+
+#[assert(size == BYTES)]
+union Buffer {
+    bytes: [u8; BYTES],
+    words: [u32; BYTES / 4],
+}
+
+#[assert(size == 1, align == 1)]
+enum Byte {
+    A,
+    B,
+}
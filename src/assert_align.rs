@@ -1,3 +1,40 @@
+/// Asserts that the alignment of a type compares to a constant.
+///
+/// The comparison is written as `T == N`, where `N` is a constant [`usize`]
+/// expression, and may use `==`, `!=`, `<=`, or `>=`. Strict comparisons are
+/// not supported since `<` and `>` cannot be told apart from generic
+/// arguments.
+///
+/// On Rust 1.64 or later, failures report the actual alignment of the type.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(align(16))]
+/// struct Simd([f32; 4]);
+///
+/// assert_align!(Simd == 16);
+/// assert_align!(Simd >= std::mem::align_of::<f32>());
+/// assert_align!([u8; 3] != 2);
+/// ```
+///
+/// The following example fails to compile, with an error saying that the
+/// alignment of `u64` is 8:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_align!(u64 <= 4);
+/// ```
+///
+/// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+#[macro_export]
+macro_rules! assert_align {
+    ($($comparison:tt)+) => {
+        $crate::_assert_layout!(align_of, "alignment", [] $($comparison)+);
+    };
+}
+
 /// Asserts that the types' alignments are equal.
 ///
 /// This is useful when ensuring that pointer arithmetic is done correctly, or
@@ -20,7 +57,8 @@
 /// ```
 ///
 /// The following example fails to compile because `i32x4` explicitly has 4
-/// times the alignment as `[i32; 4]`:
+/// times the alignment as `[i32; 4]`. On Rust 1.64 or later, the error reports
+/// both alignments, as with all alignment assertions.
 ///
 /// ```compile_fail
 /// # #[macro_use] extern crate static_assertions; fn main() {}
@@ -32,13 +70,10 @@
 /// ```
 ///
 /// [FFI]: https://en.wikipedia.org/wiki/Foreign_function_interface
-#[macro_export(local_inner_macros)]
+#[macro_export]
 macro_rules! assert_align_eq {
     ($x:ty, $($y:ty),+ $(,)?) => {
-        const _: fn() = || {
-            use $crate::_core::mem::align_of;
-            const_assert_eq_usize!(align_of::<$x>() $(, align_of::<$y>())+);
-        };
+        $($crate::_assert_layout_cmp!(align_of, ==, $x, $y);)+
     };
 }
 
@@ -75,13 +110,10 @@ macro_rules! assert_eq_align {
 /// # #[macro_use] extern crate static_assertions; fn main() {}
 /// assert_align_ne!(*const u8, usize);
 /// ```
-#[macro_export(local_inner_macros)]
+#[macro_export]
 macro_rules! assert_align_ne {
     ($x:ty, $($y:ty),+ $(,)?) => {
-        const _: fn() = || {
-            use $crate::_core::mem::align_of;
-            const_assert_ne!(align_of::<$x>() $(, align_of::<$y>())+);
-        };
+        $($crate::_assert_layout_cmp!(align_of, !=, $x, $y);)+
     };
 }
 
@@ -104,13 +136,10 @@ macro_rules! assert_align_ne {
 /// # #[macro_use] extern crate static_assertions; fn main() {}
 /// assert_align_lt!(*const u8, usize);
 /// ```
-#[macro_export(local_inner_macros)]
+#[macro_export]
 macro_rules! assert_align_lt {
     ($x:ty, $($y:ty),+ $(,)?) => {
-        const _: fn() = || {
-            use $crate::_core::mem::align_of;
-            const_assert_lt!(align_of::<$x>() $(, align_of::<$y>())+);
-        };
+        $crate::_assert_layout_chain!(align_of, <, $x $(, $y)+);
     };
 }
 
@@ -132,13 +161,10 @@ macro_rules! assert_align_lt {
 /// # #[macro_use] extern crate static_assertions; fn main() {}
 /// assert_align_le!(usize, u8);
 /// ```
-#[macro_export(local_inner_macros)]
+#[macro_export]
 macro_rules! assert_align_le {
     ($x:ty, $($y:ty),+ $(,)?) => {
-        const _: fn() = || {
-            use $crate::_core::mem::align_of;
-            const_assert_le!(align_of::<$x>() $(, align_of::<$y>())+);
-        };
+        $crate::_assert_layout_chain!(align_of, <=, $x $(, $y)+);
     };
 }
 
@@ -161,13 +187,10 @@ macro_rules! assert_align_le {
 /// # #[macro_use] extern crate static_assertions; fn main() {}
 /// assert_align_gt!(*const u8, usize);
 /// ```
-#[macro_export(local_inner_macros)]
+#[macro_export]
 macro_rules! assert_align_gt {
     ($x:ty, $($y:ty),+ $(,)?) => {
-        const _: fn() = || {
-            use $crate::_core::mem::align_of;
-            const_assert_gt!(align_of::<$x>() $(, align_of::<$y>())+);
-        };
+        $crate::_assert_layout_chain!(align_of, >, $x $(, $y)+);
     };
 }
 
@@ -189,12 +212,9 @@ macro_rules! assert_align_gt {
 /// # #[macro_use] extern crate static_assertions; fn main() {}
 /// assert_align_ge!(u8, usize);
 /// ```
-#[macro_export(local_inner_macros)]
+#[macro_export]
 macro_rules! assert_align_ge {
     ($x:ty, $($y:ty),+ $(,)?) => {
-        const _: fn() = || {
-            use $crate::_core::mem::align_of;
-            const_assert_ge!(align_of::<$x>() $(, align_of::<$y>())+);
-        };
+        $crate::_assert_layout_chain!(align_of, >=, $x $(, $y)+);
     };
 }
@@ -0,0 +1,103 @@
+/// Asserts that the type implements traits with the given associated types.
+///
+/// This pins down not only that the trait is implemented, but also what the
+/// implementation says, such as the [`Item`] of an [`Iterator`].
+///
+/// Traits are given with their associated types like in a bound, but without
+/// any generic parameters. See [`assert_impl_all!`] for traits with generic
+/// parameters.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::ops::Range;
+///
+/// assert_impl_assoc!(Vec<u8>: IntoIterator<Item = u8>);
+/// assert_impl_assoc!(&'static [u8]: IntoIterator<Item = &'static u8>);
+/// assert_impl_assoc!(
+///     Range<u8>: Iterator<Item = u8>, IntoIterator<Item = u8, IntoIter = Range<u8>>
+/// );
+/// ```
+///
+/// The following example fails to compile because iterating over a `&Vec<u8>`
+/// yields references:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_impl_assoc!(&'static Vec<u8>: IntoIterator<Item = u8>);
+/// ```
+///
+/// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+/// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+/// [`assert_impl_all!`]: macro.assert_impl_all.html
+#[macro_export]
+macro_rules! assert_impl_assoc {
+    ($ty:ty: $($($trait:ident)::+ <$($assoc:ident = $assoc_ty:ty),+ $(,)?>),+ $(,)?) => {
+        $($crate::_assert_impl_assoc!(
+            $ty, [$($trait)::+], $($assoc = $assoc_ty),+
+        );)+
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_impl_assoc {
+    ($ty:ty, [$trait:path], $($assoc:ident = $assoc_ty:ty),+) => {
+        $crate::assert_impl_all!($ty: $trait);
+        $($crate::assert_type_eq_all!(<$ty as $trait>::$assoc, $assoc_ty);)+
+    };
+}
+
+/// Asserts that an associated constant is equal to the given value.
+///
+/// This is a shorthand for [`const_assert_eq!`] with an error message that
+/// names the constant. On Rust 1.64 or later, the message also shows the
+/// value of the constant and the expected value.
+///
+/// As with [`const_assert_eq!`], the constant must be of a primitive type that
+/// can be compared via `==` in constant evaluation, such as an integer, float,
+/// `bool`, or `char`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// trait Codec {
+///     const VERSION: u32;
+///     const LOSSY: bool;
+/// }
+///
+/// struct Flac;
+///
+/// impl Codec for Flac {
+///     const VERSION: u32 = 3;
+///     const LOSSY: bool = false;
+/// }
+///
+/// assert_assoc_const_eq!(<Flac as Codec>::VERSION, 3);
+/// assert_assoc_const_eq!(<Flac as Codec>::LOSSY, false);
+/// ```
+///
+/// The following example fails to compile because the version is 3:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// # trait Codec { const VERSION: u32; }
+/// # struct Flac;
+/// # impl Codec for Flac { const VERSION: u32 = 3; }
+/// assert_assoc_const_eq!(<Flac as Codec>::VERSION, 4);
+/// ```
+///
+/// [`const_assert_eq!`]: macro.const_assert_eq.html
+#[macro_export]
+macro_rules! assert_assoc_const_eq {
+    ($constant:expr, $value:expr $(,)?) => {
+        $crate::const_assert_eq!(
+            $constant, $value;
+            concat!("`", stringify!($constant), "` is {}, expected {}"),
+            $constant,
+            $crate::_fmt::arg_like($constant, $value),
+        );
+    };
+}
@@ -0,0 +1,356 @@
+/// Asserts that types are [`Send`].
+///
+/// This is a shorthand for [`assert_impl_all!`] with better error messages,
+/// since the compiler is asked to prove [`Send`] directly. It then points out
+/// which field of the type is not [`Send`], rather than reporting a type
+/// mismatch.
+///
+/// Related:
+/// - [`assert_sync!`]
+/// - [`assert_thread_safe!`]
+/// - [`assert_not_send!`]
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Job {
+///     id: u64,
+///     payload: Vec<u8>,
+/// }
+///
+/// assert_send!(Job, String, Box<dyn Fn() + Send>);
+/// ```
+///
+/// The following example fails to compile because [`Rc`] is not [`Send`].
+/// The error notes that `Job` contains it:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::rc::Rc;
+///
+/// struct Job {
+///     id: u64,
+///     payload: Rc<[u8]>,
+/// }
+///
+/// assert_send!(Job);
+/// ```
+///
+/// The future returned by an `async fn` cannot be named as a type. Use
+/// [`assert_future_impl!`] to check it instead, such as with
+/// `assert_future_impl!(fetch(_, _): Send)`.
+///
+/// [`Send`]: https://doc.rust-lang.org/std/marker/trait.Send.html
+/// [`Rc`]: https://doc.rust-lang.org/std/rc/struct.Rc.html
+/// [`assert_impl_all!`]: macro.assert_impl_all.html
+/// [`assert_sync!`]: macro.assert_sync.html
+/// [`assert_thread_safe!`]: macro.assert_thread_safe.html
+/// [`assert_not_send!`]: macro.assert_not_send.html
+/// [`assert_future_impl!`]: macro.assert_future_impl.html
+#[macro_export]
+macro_rules! assert_send {
+    ($($t:ty),+ $(,)?) => {
+        $crate::_assert_auto!([$crate::_core::marker::Send] $($t),+);
+    };
+}
+
+/// Asserts that types are [`Sync`].
+///
+/// This works the same way as [`assert_send!`].
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::sync::{atomic::AtomicUsize, Mutex};
+///
+/// struct Counter {
+///     hits: AtomicUsize,
+///     names: Mutex<Vec<String>>,
+/// }
+///
+/// assert_sync!(Counter, &'static str);
+/// ```
+///
+/// The following example fails to compile because [`Cell`] is not [`Sync`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::cell::Cell;
+///
+/// struct Counter {
+///     hits: Cell<usize>,
+/// }
+///
+/// assert_sync!(Counter);
+/// ```
+///
+/// [`Sync`]: https://doc.rust-lang.org/std/marker/trait.Sync.html
+/// [`Cell`]: https://doc.rust-lang.org/std/cell/struct.Cell.html
+/// [`assert_send!`]: macro.assert_send.html
+#[macro_export]
+macro_rules! assert_sync {
+    ($($t:ty),+ $(,)?) => {
+        $crate::_assert_auto!([$crate::_core::marker::Sync] $($t),+);
+    };
+}
+
+/// Asserts that types are both [`Send`] and [`Sync`].
+///
+/// This works the same way as [`assert_send!`].
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::sync::{Arc, Mutex};
+///
+/// assert_thread_safe!(Arc<Mutex<Vec<u8>>>, String);
+/// ```
+///
+/// The following example fails to compile because [`Arc`] of a [`Cell`] is
+/// neither [`Send`] nor [`Sync`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::{cell::Cell, sync::Arc};
+///
+/// assert_thread_safe!(Arc<Cell<u8>>);
+/// ```
+///
+/// [`Send`]: https://doc.rust-lang.org/std/marker/trait.Send.html
+/// [`Sync`]: https://doc.rust-lang.org/std/marker/trait.Sync.html
+/// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+/// [`Cell`]: https://doc.rust-lang.org/std/cell/struct.Cell.html
+/// [`assert_send!`]: macro.assert_send.html
+#[macro_export]
+macro_rules! assert_thread_safe {
+    ($($t:ty),+ $(,)?) => {
+        $crate::_assert_auto!(
+            [$crate::_core::marker::Send + $crate::_core::marker::Sync] $($t),+
+        );
+    };
+}
+
+/// Asserts that types are [`Unpin`].
+///
+/// This works the same way as [`assert_send!`].
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::{future::Future, pin::Pin};
+///
+/// assert_unpin!(Box<u8>, Pin<Box<dyn Future<Output = ()>>>);
+/// ```
+///
+/// The following example fails to compile because [`PhantomPinned`] is not
+/// [`Unpin`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::marker::PhantomPinned;
+///
+/// struct Node {
+///     value: u32,
+///     _pin: PhantomPinned,
+/// }
+///
+/// assert_unpin!(Node);
+/// ```
+///
+/// [`Unpin`]: https://doc.rust-lang.org/std/marker/trait.Unpin.html
+/// [`PhantomPinned`]: https://doc.rust-lang.org/std/marker/struct.PhantomPinned.html
+/// [`assert_send!`]: macro.assert_send.html
+#[macro_export]
+macro_rules! assert_unpin {
+    ($($t:ty),+ $(,)?) => {
+        $crate::_assert_auto!([$crate::_core::marker::Unpin] $($t),+);
+    };
+}
+
+/// Asserts that types are [`UnwindSafe`].
+///
+/// This works the same way as [`assert_send!`].
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::{cell::Cell, panic::AssertUnwindSafe};
+///
+/// assert_unwind_safe!(Vec<u8>, AssertUnwindSafe<&Cell<u8>>);
+/// ```
+///
+/// The following example fails to compile because a shared reference to a
+/// [`Cell`] may observe a broken invariant after a panic:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::cell::Cell;
+///
+/// assert_unwind_safe!(&'static Cell<u8>);
+/// ```
+///
+/// This macro requires Rust 1.56 or later.
+///
+/// [`UnwindSafe`]: https://doc.rust-lang.org/std/panic/trait.UnwindSafe.html
+/// [`Cell`]: https://doc.rust-lang.org/std/cell/struct.Cell.html
+/// [`assert_send!`]: macro.assert_send.html
+#[cfg(core_unwind_safe)]
+#[macro_export]
+macro_rules! assert_unwind_safe {
+    ($($t:ty),+ $(,)?) => {
+        $crate::_assert_auto!([$crate::_core::panic::UnwindSafe] $($t),+);
+    };
+}
+
+/// Asserts that types are _not_ [`Send`].
+///
+/// This is a shorthand for [`assert_impl_not_any!`], which is useful for
+/// ensuring that a type stays on the thread that created it.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::{marker::PhantomData, rc::Rc};
+///
+/// // Must be dropped on the thread that acquired it.
+/// struct Guard {
+///     _not_send: PhantomData<*const ()>,
+/// }
+///
+/// assert_not_send!(Guard, Rc<u8>);
+/// ```
+///
+/// The following example fails to compile because [`u8`] is [`Send`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_not_send!(u8);
+/// ```
+///
+/// [`Send`]: https://doc.rust-lang.org/std/marker/trait.Send.html
+/// [`u8`]: https://doc.rust-lang.org/std/primitive.u8.html
+/// [`assert_impl_not_any!`]: macro.assert_impl_not_any.html
+#[macro_export]
+macro_rules! assert_not_send {
+    ($($t:ty),+ $(,)?) => {
+        $($crate::assert_impl_not_any!($t: $crate::_core::marker::Send);)+
+    };
+}
+
+/// Asserts that types are _not_ [`Sync`].
+///
+/// This is a shorthand for [`assert_impl_not_any!`].
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::cell::{Cell, RefCell};
+///
+/// assert_not_sync!(Cell<u8>, RefCell<String>);
+/// ```
+///
+/// The following example fails to compile because [`u8`] is [`Sync`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_not_sync!(u8);
+/// ```
+///
+/// [`Sync`]: https://doc.rust-lang.org/std/marker/trait.Sync.html
+/// [`u8`]: https://doc.rust-lang.org/std/primitive.u8.html
+/// [`assert_impl_not_any!`]: macro.assert_impl_not_any.html
+#[macro_export]
+macro_rules! assert_not_sync {
+    ($($t:ty),+ $(,)?) => {
+        $($crate::assert_impl_not_any!($t: $crate::_core::marker::Sync);)+
+    };
+}
+
+/// Asserts that types are _not_ [`Unpin`].
+///
+/// This is a shorthand for [`assert_impl_not_any!`], which is useful for
+/// self-referential types that must stay pinned.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::marker::PhantomPinned;
+///
+/// struct Node {
+///     value: u32,
+///     _pin: PhantomPinned,
+/// }
+///
+/// assert_not_unpin!(Node);
+/// ```
+///
+/// The following example fails to compile because [`u8`] is [`Unpin`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_not_unpin!(u8);
+/// ```
+///
+/// [`Unpin`]: https://doc.rust-lang.org/std/marker/trait.Unpin.html
+/// [`u8`]: https://doc.rust-lang.org/std/primitive.u8.html
+/// [`assert_impl_not_any!`]: macro.assert_impl_not_any.html
+#[macro_export]
+macro_rules! assert_not_unpin {
+    ($($t:ty),+ $(,)?) => {
+        $($crate::assert_impl_not_any!($t: $crate::_core::marker::Unpin);)+
+    };
+}
+
+/// Asserts that types are _not_ [`UnwindSafe`].
+///
+/// This is a shorthand for [`assert_impl_not_any!`].
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::cell::Cell;
+///
+/// assert_not_unwind_safe!(&'static Cell<u8>, &'static mut u8);
+/// ```
+///
+/// The following example fails to compile because [`u8`] is [`UnwindSafe`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_not_unwind_safe!(u8);
+/// ```
+///
+/// This macro requires Rust 1.56 or later.
+///
+/// [`UnwindSafe`]: https://doc.rust-lang.org/std/panic/trait.UnwindSafe.html
+/// [`u8`]: https://doc.rust-lang.org/std/primitive.u8.html
+/// [`assert_impl_not_any!`]: macro.assert_impl_not_any.html
+#[cfg(core_unwind_safe)]
+#[macro_export]
+macro_rules! assert_not_unwind_safe {
+    ($($t:ty),+ $(,)?) => {
+        $($crate::assert_impl_not_any!($t: $crate::_core::panic::UnwindSafe);)+
+    };
+}
+
+/// Asserts that types implement the given bounds, which the compiler is asked
+/// to prove directly.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_auto {
+    ([$($bound:tt)+] $($t:ty),+ $(,)?) => {
+        const _: fn() = || {
+            fn assert_impl<T: ?Sized + $($bound)+>() {}
+            $(assert_impl::<$t>();)+
+        };
+    };
+}
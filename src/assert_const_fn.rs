@@ -0,0 +1,63 @@
+/// Asserts that function calls can be evaluated in constant context.
+///
+/// Each call is evaluated in a `const` item, which fails to compile if the
+/// function is not a [`const fn`]. This is useful for APIs that promise to be
+/// usable in constants, since removing `const` from a function is a breaking
+/// change that nothing else in the defining crate would catch.
+///
+/// Arguments must be constant expressions. The results are never dropped, so
+/// they may be of any type.
+///
+/// See [`const_assert!`] for asserting on the results of calls.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// pub struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// impl Point {
+///     pub const fn new(x: i32, y: i32) -> Self {
+///         Point { x, y }
+///     }
+/// }
+///
+/// assert_const_fn!(Point::new(1, 2));
+/// assert_const_fn!(u32::max_value(), std::cell::Cell::new(1u8));
+/// ```
+///
+/// The following example fails to compile because `Point::new` is not a
+/// [`const fn`], which the compiler reports as a call to a non-const function
+/// in a constant:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// pub struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// impl Point {
+///     pub fn new(x: i32, y: i32) -> Self {
+///         Point { x, y }
+///     }
+/// }
+///
+/// assert_const_fn!(Point::new(1, 2));
+/// ```
+///
+/// [`const fn`]: https://doc.rust-lang.org/reference/const_eval.html#const-functions
+/// [`const_assert!`]: macro.const_assert.html
+#[macro_export]
+macro_rules! assert_const_fn {
+    ($($call:expr),+ $(,)?) => {
+        $(const _: () = {
+            // Results are wrapped so that types with destructors, which cannot
+            // run in constant evaluation, are allowed too.
+            let _ = $crate::_core::mem::ManuallyDrop::new($call);
+        };)+
+    };
+}
@@ -0,0 +1,159 @@
+/// Asserts that `enum` variants have the given discriminant values.
+///
+/// This is useful for fieldless `enum` types whose values are sent over the
+/// wire or shared with foreign code, where changing a discriminant silently
+/// breaks compatibility.
+///
+/// Discriminants are compared as [`i128`], so any integer literal can be used
+/// regardless of the `enum`'s `#[repr]`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(u8)]
+/// enum Opcode {
+///     Ping = 0x01,
+///     Pong,
+///     Close = 0x10,
+/// }
+///
+/// assert_discriminant!(Opcode::Ping == 0x01, Opcode::Pong == 0x02);
+/// assert_discriminant!(Opcode::Close == 16);
+/// ```
+///
+/// The following example fails to compile because `Pong` implicitly has a
+/// discriminant of 1:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// enum Opcode {
+///     Ping,
+///     Pong,
+/// }
+///
+/// assert_discriminant!(Opcode::Pong == 2);
+/// ```
+///
+/// [`i128`]: https://doc.rust-lang.org/std/primitive.i128.html
+#[macro_export]
+macro_rules! assert_discriminant {
+    ($($head:ident $(:: $tail:ident)* == $value:expr),+ $(,)?) => {
+        $($crate::const_assert_eq!(
+            $head $(:: $tail)* as i128, ($value) as i128;
+            concat!(
+                "`", stringify!($head), $("::", stringify!($tail),)*
+                "` has discriminant {}, expected {}"
+            ),
+            $head $(:: $tail)* as i128,
+            ($value) as i128,
+        );)+
+    };
+}
+
+/// Asserts that the `enum` has exactly the given variants, with contiguous
+/// discriminants in the given order.
+///
+/// As with an `enum` definition, the first discriminant is 0 unless given
+/// explicitly. Because every variant must be listed, this also ensures that
+/// all discriminants are unique.
+///
+/// # Examples
+///
+/// This is useful for keeping lookup tables indexed by discriminant in sync
+/// with the `enum`:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[derive(Clone, Copy)]
+/// enum Level {
+///     Trace,
+///     Debug,
+///     Info,
+/// }
+///
+/// const NAMES: [&str; 3] = ["trace", "debug", "info"];
+///
+/// assert_discriminants_contiguous!(Level: Trace, Debug, Info);
+/// ```
+///
+/// Discriminants can start at any value:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(u8)]
+/// enum Opcode {
+///     Ping = 0x01,
+///     Pong,
+///     Close,
+/// }
+///
+/// assert_discriminants_contiguous!(Opcode: Ping = 0x01, Pong, Close);
+/// ```
+///
+/// The following example fails to compile because there is a gap between
+/// `Pong` and `Close`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(u8)]
+/// enum Opcode {
+///     Ping = 0x01,
+///     Pong,
+///     Close = 0x10,
+/// }
+///
+/// assert_discriminants_contiguous!(Opcode: Ping = 0x01, Pong, Close);
+/// ```
+///
+/// The following example fails to compile because `Close` is not listed:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// enum Opcode {
+///     Ping,
+///     Pong,
+///     Close,
+/// }
+///
+/// assert_discriminants_contiguous!(Opcode: Ping, Pong);
+/// ```
+#[macro_export]
+macro_rules! assert_discriminants_contiguous {
+    ($t:ty: $first:ident $(, $v:ident)* $(,)?) => {
+        $crate::assert_discriminants_contiguous!($t: $first = 0 $(, $v)*);
+    };
+    ($t:ty: $first:ident = $start:expr $(, $v:ident)* $(,)?) => {
+        const _: () = {
+            type Enum = $t;
+
+            // Fails if any variant is not listed.
+            #[allow(dead_code, unreachable_patterns)]
+            fn assert(value: Enum) {
+                match value {
+                    Enum::$first => {}
+                    $(Enum::$v => {})*
+                }
+            }
+
+            $crate::assert_discriminants_contiguous!(
+                @check $t, Enum, ($start) as i128, $first $(, $v)*
+            );
+        };
+    };
+    (@check $t:ty, $enum:ident, $n:expr, $v:ident $(, $vs:ident)*) => {
+        $crate::const_assert_eq!(
+            $enum::$v as i128, $n;
+            concat!(
+                "`", stringify!($t), "::", stringify!($v),
+                "` has discriminant {}, expected {}"
+            ),
+            $enum::$v as i128,
+            $n,
+        );
+        $crate::assert_discriminants_contiguous!(
+            @check $t, $enum, $n + 1 $(, $vs)*
+        );
+    };
+    (@check $t:ty, $enum:ident, $n:expr) => {};
+}
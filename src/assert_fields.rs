@@ -70,3 +70,168 @@ macro_rules! assert_fields {
         };
     };
 }
+
+/// Asserts that the type has the given fields, each of the given type.
+///
+/// This is an extension of [`assert_fields!`] that also catches changes to the
+/// types of fields, such as in a dependency. Field types are compared the same
+/// way as in [`assert_type_eq_all!`].
+///
+/// # Examples
+///
+/// Fields are given as `name: Type` pairs:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Config {
+///     port: u16,
+///     host: String,
+///     verbose: bool,
+/// }
+///
+/// assert_fields_ty!(Config: port: u16, host: String);
+/// ```
+///
+/// Like [`assert_fields!`], this works with `enum` variants. Tuple fields can
+/// be referred to by their index:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// enum Message {
+///     Move { x: i32, y: i32 },
+///     Write(String),
+/// }
+///
+/// assert_fields_ty!(Message::Move: x: i32, y: i32);
+/// assert_fields_ty!(Message::Write: 0: String);
+/// ```
+///
+/// The following example fails to compile because the `start` of a
+/// [`Range<u32>`][`Range`] is not a `u64`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::ops::Range;
+///
+/// assert_fields_ty!(Range<u32>: start: u64);
+/// ```
+///
+/// [`assert_fields!`]: macro.assert_fields.html
+/// [`assert_type_eq_all!`]: macro.assert_type_eq_all.html
+/// [`Range`]: https://doc.rust-lang.org/std/ops/struct.Range.html
+#[macro_export]
+macro_rules! assert_fields_ty {
+    ($t:ident::$v:ident: $($f:tt: $ty:ty),+ $(,)?) => {
+        $crate::assert_fields_ty!(@assert $t, value => {
+            #[allow(unreachable_patterns)]
+            match *value {
+                $($t::$v { $f: ref field, .. } => {
+                    assert_field_ty::<_, $ty>(field);
+                })+
+                _ => {}
+            }
+        });
+    };
+    ($t:path: $($f:tt: $ty:ty),+ $(,)?) => {
+        $crate::assert_fields_ty!(@assert $t, value => {
+            $({
+                let $t { $f: ref field, .. } = *value;
+                assert_field_ty::<_, $ty>(field);
+            })+
+        });
+    };
+    (@assert $t:ty, $value:ident => $body:block) => {
+        #[allow(unknown_lints, unneeded_field_pattern)]
+        const _: fn() = || {
+            trait TypeEq {
+                type This: ?Sized;
+            }
+
+            impl<T: ?Sized> TypeEq for T {
+                type This = Self;
+            }
+
+            // The field's type `T` is inferred from the reference.
+            fn assert_field_ty<T, U>(_: &T)
+            where
+                T: ?Sized + TypeEq<This = U>,
+                U: ?Sized,
+            {}
+
+            #[allow(dead_code)]
+            fn assert($value: &$t) $body
+        };
+    };
+}
+
+/// Asserts that the type has _exactly_ the given fields.
+///
+/// Unlike [`assert_fields!`], this fails when the type has any field that is
+/// not listed. This is useful for code that must be updated whenever a type
+/// gains a field, such as serialization.
+///
+/// # Examples
+///
+/// The order of fields does not matter:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Packet {
+///     id: u32,
+///     len: u16,
+///     payload: Vec<u8>,
+/// }
+///
+/// assert_fields_exact!(Packet: id, payload, len);
+/// ```
+///
+/// Tuple structs and `enum` variants are also supported. Tuple fields are
+/// referred to by their index:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Rgb(u8, u8, u8);
+///
+/// enum Shape {
+///     Circle { radius: f32 },
+///     Rect(f32, f32),
+/// }
+///
+/// assert_fields_exact!(Rgb: 0, 1, 2);
+/// assert_fields_exact!(Shape::Circle: radius);
+/// assert_fields_exact!(Shape::Rect: 0, 1);
+/// ```
+///
+/// The following example fails to compile because `payload` is not listed:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Packet {
+///     id: u32,
+///     len: u16,
+///     payload: Vec<u8>,
+/// }
+///
+/// assert_fields_exact!(Packet: id, len);
+/// ```
+///
+/// [`assert_fields!`]: macro.assert_fields.html
+#[macro_export]
+macro_rules! assert_fields_exact {
+    ($t:ident::$v:ident: $($f:tt),+ $(,)?) => {
+        const _: fn() = || {
+            #[allow(dead_code, unreachable_patterns)]
+            fn assert(value: $t) {
+                match value {
+                    $t::$v { $($f: _),+ } => {}
+                    _ => {}
+                }
+            }
+        };
+    };
+    ($t:path: $($f:tt),+ $(,)?) => {
+        const _: fn() = || {
+            let $t { $($f: _),+ };
+        };
+    };
+}
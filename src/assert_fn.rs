@@ -0,0 +1,89 @@
+/// Asserts that functions have the given signatures.
+///
+/// Each function is coerced to the given function pointer type, which fails
+/// to compile if its parameters, return type, or [ABI] differ. This pins
+/// signatures where they matter, such as for callback tables or functions
+/// exported over [FFI], so that a change is reported at the definition
+/// rather than wherever the function is used.
+///
+/// Generic functions may be instantiated explicitly or have their parameters
+/// inferred from the signature. Methods are given by their path, with `self`
+/// as the first parameter.
+///
+/// Since this is a coercion, safe functions are accepted for `unsafe fn`
+/// pointers, and parameters may be more general than required, such as `&str`
+/// for `&'static str`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Request;
+/// struct Response;
+///
+/// fn handle(_: &Request) -> Response {
+///     // ...
+/// #   Response
+/// }
+///
+/// assert_fn_sig!(handle: fn(&Request) -> Response);
+/// assert_fn_sig!(
+///     str::len: fn(&str) -> usize,
+///     u8::checked_add: fn(u8, u8) -> Option<u8>,
+/// );
+/// ```
+///
+/// Generic functions can be checked for specific parameters:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::mem;
+///
+/// assert_fn_sig!(mem::size_of::<u32>: fn() -> usize);
+/// assert_fn_sig!(mem::swap: fn(&mut u8, &mut u8));
+/// ```
+///
+/// `unsafe` and `extern` functions must be given as such:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[no_mangle]
+/// pub extern "C" fn checksum(data: *const u8, len: usize) -> u32 {
+///     // ...
+/// #   let _ = (data, len);
+/// #   0
+/// }
+///
+/// assert_fn_sig!(checksum: extern "C" fn(*const u8, usize) -> u32);
+/// assert_fn_sig!(std::ptr::read::<u64>: unsafe fn(*const u64) -> u64);
+/// ```
+///
+/// The following example fails to compile because `checksum` does not use the
+/// C ABI:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// pub fn checksum(data: *const u8, len: usize) -> u32 {
+///     // ...
+/// #   let _ = (data, len);
+/// #   0
+/// }
+///
+/// assert_fn_sig!(checksum: extern "C" fn(*const u8, usize) -> u32);
+/// ```
+///
+/// The following example fails to compile because the return type differs:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_fn_sig!(str::len: fn(&str) -> u32);
+/// ```
+///
+/// [ABI]: https://doc.rust-lang.org/reference/items/external-blocks.html#abi
+/// [FFI]: https://en.wikipedia.org/wiki/Foreign_function_interface
+#[macro_export]
+macro_rules! assert_fn_sig {
+    ($($f:path: $sig:ty),+ $(,)?) => {
+        $(const _: $sig = $f;)+
+    };
+}
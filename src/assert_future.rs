@@ -0,0 +1,147 @@
+/// Asserts that the future returned by a function call satisfies a trait
+/// expression.
+///
+/// This is meant for `async fn`s, whose futures silently lose [`Send`] when a
+/// value that is not [`Send`] is held across an `.await`. The type of such a
+/// future cannot be named, so [`assert_impl!`] cannot be used on it.
+///
+/// The call is written as usual, except that arguments may be `_`
+/// placeholders for a value of any type. The function is never called. The
+/// trait expression has the same grammar as in [`assert_impl!`], so traits may
+/// be combined with operators such as `+` and `!`, and lifetimes like
+/// `'static` may be required.
+///
+/// # Examples
+///
+/// ```edition2018
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// async fn fetch(url: String, retries: u32) -> Vec<u8> {
+///     // ...
+/// #   let _ = (url, retries);
+/// #   Vec::new()
+/// }
+///
+/// struct Client;
+///
+/// impl Client {
+///     async fn get<T: Default>(&self, key: &str) -> T {
+///         // ...
+/// #       let _ = key;
+/// #       T::default()
+///     }
+/// }
+///
+/// assert_future_impl!(fetch(_, _): Send + 'static);
+/// assert_future_impl!(fetch(String::new(), 3): Send & !Unpin);
+/// assert_future_impl!(Client::get::<u64>(_, "key"): Send + Sync);
+/// ```
+///
+/// When the traits are only combined with `+`, they are checked as a plain
+/// bound, so a failure gets the compiler's usual diagnostic for futures. The
+/// following example fails to compile with "future cannot be sent between
+/// threads safely", pointing at the `.await` across which an [`Rc`] is held:
+///
+/// ```compile_fail,edition2018
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::rc::Rc;
+///
+/// async fn yield_now() {}
+///
+/// async fn count() -> usize {
+///     let shared = Rc::new(());
+///     yield_now().await;
+///     Rc::strong_count(&shared)
+/// }
+///
+/// assert_future_impl!(count(): Send);
+/// ```
+///
+/// The function must return a [`Future`]:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// fn parse(_: &str) -> u32 {
+///     0
+/// }
+///
+/// assert_future_impl!(parse(_): Send);
+/// ```
+///
+/// [`Send`]: https://doc.rust-lang.org/std/marker/trait.Send.html
+/// [`Future`]: https://doc.rust-lang.org/std/future/trait.Future.html
+/// [`Rc`]: https://doc.rust-lang.org/std/rc/struct.Rc.html
+/// [`assert_impl!`]: macro.assert_impl.html
+#[macro_export]
+macro_rules! assert_future_impl {
+    (@split [$($call:tt)+] : $($rest:tt)+) => {
+        $crate::assert_future_impl!(@bound [$($call)+] [$($rest)+] $($rest)+);
+    };
+    (@split [$($call:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::assert_future_impl!(@split [$($call)* $next] $($rest)*);
+    };
+    (@split [$($call:tt)*]) => {
+        compile_error!(concat!(
+            "expected `: <trait_expr>` after `", stringify!($($call)*), "`"
+        ));
+    };
+    // Expressions with operators other than `+` or with parentheses are
+    // evaluated as a value, everything else is used as a plain bound.
+    (@bound $call:tt $expr:tt ! $($rest:tt)*) => {
+        $crate::assert_future_impl!(@value $call $expr);
+    };
+    (@bound $call:tt $expr:tt & $($rest:tt)*) => {
+        $crate::assert_future_impl!(@value $call $expr);
+    };
+    (@bound $call:tt $expr:tt && $($rest:tt)*) => {
+        $crate::assert_future_impl!(@value $call $expr);
+    };
+    (@bound $call:tt $expr:tt | $($rest:tt)*) => {
+        $crate::assert_future_impl!(@value $call $expr);
+    };
+    (@bound $call:tt $expr:tt || $($rest:tt)*) => {
+        $crate::assert_future_impl!(@value $call $expr);
+    };
+    (@bound $call:tt $expr:tt ^ $($rest:tt)*) => {
+        $crate::assert_future_impl!(@value $call $expr);
+    };
+    (@bound $call:tt $expr:tt => $($rest:tt)*) => {
+        $crate::assert_future_impl!(@value $call $expr);
+    };
+    (@bound $call:tt $expr:tt ($($group:tt)*) $($rest:tt)*) => {
+        $crate::assert_future_impl!(@value $call $expr);
+    };
+    (@bound $call:tt $expr:tt $next:tt $($rest:tt)*) => {
+        $crate::assert_future_impl!(@bound $call $expr $($rest)*);
+    };
+    (@bound [$($call:tt)+] [$($bound:tt)+]) => {
+        const _: fn() = || {
+            #[allow(dead_code)]
+            fn placeholder<T>() -> T {
+                unreachable!()
+            }
+            fn into_future<F: $crate::_core::future::Future>(future: F) -> F {
+                future
+            }
+            fn assert_impl<T: ?Sized + $($bound)+>(_: &T) {}
+
+            assert_impl(&into_future($crate::_call_placeholders!([] $($call)+)));
+        };
+    };
+    (@value [$($call:tt)+] [$($rest:tt)+]) => {
+        const _: fn() = || {
+            #[allow(dead_code)]
+            fn placeholder<T>() -> T {
+                unreachable!()
+            }
+            fn into_future<F: $crate::_core::future::Future>(future: F) -> F {
+                future
+            }
+
+            let future = into_future($crate::_call_placeholders!([] $($call)+));
+            let _: $crate::True = $crate::_does_impl!(@value future: $($rest)+);
+        };
+    };
+    ($($tokens:tt)+) => {
+        $crate::assert_future_impl!(@split [] $($tokens)+);
+    };
+}
@@ -49,35 +49,57 @@
 /// assert_impl_one!(Foo: Snap, Crackle, Pop);
 /// ```
 ///
+/// Generic types can be checked with a `for<...>` prefix, just like with
+/// [`assert_impl_all!`]:
+///
+/// ```
+/// # use static_assertions::assert_impl_one; fn main() {}
+/// assert_impl_one!(for<T: Clone> Vec<T>: Clone, Copy);
+/// ```
+///
 /// [`assert_impl_any!`]:     macro.assert_impl_any.html
 /// [`assert_impl_all!`]:     macro.assert_impl_all.html
 /// [`assert_impl_not_all!`]: macro.assert_not_impl_all.html
 /// [`assert_impl_not_any!`]: macro.assert_not_impl_any.html
 #[macro_export]
 macro_rules! assert_impl_one {
-    ($x:ty: $($t:path),+ $(,)?) => {
-        const _: fn() = || {
-            // Generic trait that must be implemented for `$x` exactly once.
-            trait AmbiguousIfMoreThanOne<A> {
-                // Required for actually being able to reference the trait.
-                fn some_item() {}
-            }
+    (@check $x:ty: $($t:path),+) => {
+        // Generic trait that must be implemented for `$x` exactly once.
+        trait AmbiguousIfMoreThanOne<A> {
+            // Required for actually being able to reference the trait.
+            fn some_item() {}
+        }
 
-            // Creates multiple scoped `Token` types for each trait `$t`, over
-            // which a specialized `AmbiguousIfMoreThanOne<Token>` is
-            // implemented for every type that implements `$t`.
-            $({
-                #[allow(dead_code)]
-                struct Token;
+        // Creates multiple scoped `Token` types for each trait `$t`, over
+        // which a specialized `AmbiguousIfMoreThanOne<Token>` is implemented
+        // for every type that implements `$t`.
+        $({
+            #[allow(dead_code)]
+            struct Token;
 
-                impl<T: ?Sized + $t> AmbiguousIfMoreThanOne<Token> for T {}
-            })+
+            impl<T: ?Sized + $t> AmbiguousIfMoreThanOne<Token> for T {}
+        })+
 
-            // If there is only one specialized trait impl, type inference with
-            // `_` can be resolved and this can compile. Fails to compile if
-            // `$x` implements more than one `AmbiguousIfMoreThanOne<Token>` or
-            // does not implement any at all.
-            let _ = <$x as AmbiguousIfMoreThanOne<_>>::some_item;
+        // If there is only one specialized trait impl, type inference with
+        // `_` can be resolved and this can compile. Fails to compile if `$x`
+        // implements more than one `AmbiguousIfMoreThanOne<Token>` or does
+        // not implement any at all.
+        let _ = <$x as AmbiguousIfMoreThanOne<_>>::some_item;
+    };
+    (for<$($rest:tt)*) => {
+        $crate::_for_generics!(assert_impl_one [] [] $($rest)*);
+    };
+    (for($($generic:tt)*) where($($bound:tt)*) $x:ty: $($t:path),+ $(,)?) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_impl_one<$($generic)*>() where $($bound)* {
+                $crate::assert_impl_one!(@check $x: $($t),+);
+            }
+        };
+    };
+    ($x:ty: $($t:path),+ $(,)?) => {
+        const _: fn() = || {
+            $crate::assert_impl_one!(@check $x: $($t),+);
         };
     };
 }
@@ -105,12 +127,44 @@ macro_rules! assert_impl_one {
 /// assert_impl_all!(*const u8: Send);
 /// ```
 ///
+/// Generic types can be checked for _all_ parameters satisfying some bounds by
+/// prefixing them with `for<...>`, optionally followed by a `where` clause at
+/// the end. See [`assert_impl!`] for details.
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::sync::Arc;
+///
+/// struct Shared<T>(Arc<T>);
+///
+/// assert_impl_all!(for<T: Send + Sync> Shared<T>: Send, Sync);
+/// assert_impl_all!(for<T> Arc<T>: Send where T: Send + Sync);
+/// ```
+///
+/// The following example fails to compile because [`Arc`] is not [`Send`]
+/// unless its contents are [`Sync`] as well:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::sync::Arc;
+///
+/// assert_impl_all!(for<T: Send> Arc<T>: Send);
+/// ```
+///
 /// [`assert_impl_not_all!`]: macro.assert_not_impl_all.html
+/// [`assert_impl!`]: macro.assert_impl.html
+/// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
 /// [`Send`]: https://doc.rust-lang.org/std/marker/trait.Send.html
 /// [`Sync`]: https://doc.rust-lang.org/std/marker/trait.Sync.html
 /// [blanket]: https://doc.rust-lang.org/book/ch10-02-traits.html#using-trait-bounds-to-conditionally-implement-methods
 #[macro_export(local_inner_macros)]
 macro_rules! assert_impl_all {
+    (for<$($rest:tt)*) => {
+        _for_generics!(assert_impl_all [] [] $($rest)*);
+    };
+    (for($($generic:tt)*) where($($bound:tt)*) $ty:ty: $($traits:path),+ $(,)?) => {
+        assert_impl!(for($($generic)*) where($($bound)*) $ty: $( ($traits) )&+);
+    };
     ($ty:ty: $($traits:path),+ $(,)?) => {
         assert_impl!($ty: $( ($traits) )&+);
     };
@@ -146,11 +200,28 @@ macro_rules! assert_impl_all {
 /// assert_impl_any!(*const u8: Send, Sync);
 /// ```
 ///
+/// Generic types can be checked with a `for<...>` prefix, just like with
+/// [`assert_impl_all!`]:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::rc::Rc;
+///
+/// assert_impl_any!(for<T> Rc<T>: Send, Clone);
+/// ```
+///
 /// [`assert_impl_not_any!`]: macro.assert_not_impl_any.html
+/// [`assert_impl_all!`]: macro.assert_impl_all.html
 /// [`Send`]: https://doc.rust-lang.org/std/marker/trait.Send.html
 /// [`Sync`]: https://doc.rust-lang.org/std/marker/trait.Sync.html
 #[macro_export(local_inner_macros)]
 macro_rules! assert_impl_any {
+    (for<$($rest:tt)*) => {
+        _for_generics!(assert_impl_any [] [] $($rest)*);
+    };
+    (for($($generic:tt)*) where($($bound:tt)*) $ty:ty: $($traits:path),+ $(,)?) => {
+        assert_impl!(for($($generic)*) where($($bound)*) $ty: $( ($traits) )|+);
+    };
     ($ty:ty: $($traits:path),+ $(,)?) => {
         assert_impl!($ty: $( ($traits) )|+);
     };
@@ -199,6 +270,14 @@ macro_rules! assert_impl_any {
 /// assert_impl_not_all!(Cell<u32>: Send);
 /// ```
 ///
+/// With a `for<...>` prefix, the assertion holds if the traits are not all
+/// implemented for _some_ parameters satisfying the bounds:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_impl_not_all!(for<T: Clone> Vec<T>: Clone, Copy);
+/// ```
+///
 /// [`Send`]: https://doc.rust-lang.org/std/marker/trait.Send.html
 /// [`Sync`]: https://doc.rust-lang.org/std/marker/trait.Sync.html
 /// [`assert_impl_not_any!`]: macro.assert_impl_not_any.html
@@ -206,6 +285,12 @@ macro_rules! assert_impl_any {
 /// [blanket]: https://doc.rust-lang.org/book/ch10-02-traits.html#using-trait-bounds-to-conditionally-implement-methods
 #[macro_export(local_inner_macros)]
 macro_rules! assert_impl_not_all {
+    (for<$($rest:tt)*) => {
+        _for_generics!(assert_impl_not_all [] [] $($rest)*);
+    };
+    (for($($generic:tt)*) where($($bound:tt)*) $ty:ty: $($traits:path),+ $(,)?) => {
+        assert_impl!(for($($generic)*) where($($bound)*) $ty: !( $( ($traits) )&+ ));
+    };
     ($ty:ty: $($traits:path),+ $(,)?) => {
         assert_impl!($ty: !( $( ($traits) )&+ ));
     };
@@ -261,12 +346,28 @@ macro_rules! assert_not_impl_all {
 /// assert_impl_not_any!(u32: Into<u64>, Into<u16>);
 /// ```
 ///
+/// With a `for<...>` prefix, each trait must not be implemented for _some_
+/// parameters satisfying the bounds:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::rc::Rc;
+///
+/// assert_impl_not_any!(for<T: Send + Sync> Rc<T>: Send, Sync);
+/// ```
+///
 /// [`Send`]: https://doc.rust-lang.org/std/marker/trait.Send.html
 /// [`Sync`]: https://doc.rust-lang.org/std/marker/trait.Sync.html
 /// [`assert_impl_not_all!`]: macro.assert_impl_not_all.html
 /// [blanket]: https://doc.rust-lang.org/book/ch10-02-traits.html#using-trait-bounds-to-conditionally-implement-methods
 #[macro_export(local_inner_macros)]
 macro_rules! assert_impl_not_any {
+    (for<$($rest:tt)*) => {
+        _for_generics!(assert_impl_not_any [] [] $($rest)*);
+    };
+    (for($($generic:tt)*) where($($bound:tt)*) $ty:ty: $($traits:path),+ $(,)?) => {
+        assert_impl!(for($($generic)*) where($($bound)*) $ty: !( $( ($traits) )|+ ));
+    };
     ($ty:ty: $($traits:path),+ $(,)?) => {
         assert_impl!($ty: !( $( ($traits) )|+ ));
     };
@@ -298,26 +399,49 @@ macro_rules! assert_not_impl_any {
 ///
 /// ```skip
 /// assert_impl!(<type>: <trait_expr>);
-/// assert_impl!(for(<type>: <bounds>) <type>: <trait_expr>);
+/// assert_impl!(for<<generics>> <type>: <trait_expr>);
+/// assert_impl!(for<<generics>> <type>: <trait_expr> where <bounds>);
 /// ```
 ///
 /// where:
 ///
-/// - `<type>` is a type (that must not depend on a generic parameter)
+/// - `<type>` is a type, which may only depend on `<generics>`
+///
+/// - `<trait_expr>` is a boolean expression over traits, described below.
+///
+/// - `<generics>` are generic parameters with optional bounds, like in a
+///   function definition. The assertion must then hold for _all_ types
+///   satisfying those bounds.
 ///
-/// - `<trait_expr>` is an expression made out of trait names, combined with `!`
-///   for negation, `&` for conjunction, `|` for disjunction and parentheses for
-///   grouping.
+/// - `<bounds>` is a `where` clause over `<generics>`.
 ///
-/// - `<bounds>` is a trait bounds expression.
+/// A trait expression is made out of the following:
 ///
-/// For technical reasons:
+/// | Syntax       | Meaning                                      |
+/// | ------------ | -------------------------------------------- |
+/// | `Trait`      | the type implements `Trait`                  |
+/// | `'a`         | the type outlives `'a`                       |
+/// | `(x)`        | grouping                                     |
+/// | `!x`         | negation: `x` does not hold                  |
+/// | `x & y`      | conjunction: both hold                       |
+/// | `x + y`      | same as `x & y`, like in trait bounds        |
+/// | `x ^ y`      | exclusive disjunction: exactly one holds     |
+/// | `x \| y`     | disjunction: at least one holds              |
+/// | `x => y`     | implication: if `x` holds, then `y` does too |
 ///
-/// - Traits (like `Into<u8>`) that are not a single identifier must be
-///   surrounded by parentheses.
+/// Binary operators are right-associative, so `x => y => z` is parsed as
+/// `x => (y => z)`. This also applies across different operators, so `x & y |
+/// z` is parsed as `x & (y | z)` rather than following the precedence of
+/// Rust's operators. Mixing different operators without parentheses is
+/// deprecated, except for `&` and `+`, which mean the same. Write either `(x &
+/// y) | z` or `x & (y | z)` instead.
 ///
-/// - The usual operator priority is not respected: `x & y | z` is parsed as
-///   `x & (y | z)`.
+/// Traits may be any path, including generic arguments like `Into<u8>` and
+/// `Fn(&str) -> bool`, but they cannot refer to `<generics>`. A type that does
+/// not outlive a lifetime is reported as a lifetime error rather than as the
+/// expression not holding, so lifetimes should not be negated. Malformed
+/// expressions, such as `&&` in place of `&` or an operator without operands,
+/// are rejected with an error.
 ///
 /// # Examples
 ///
@@ -326,7 +450,7 @@ macro_rules! assert_not_impl_any {
 ///
 /// ```
 /// # #[macro_use] extern crate static_assertions; fn main() {}
-/// assert_impl!(u32: !((Into<usize>) & (Into<u8>)));
+/// assert_impl!(u32: !(Into<usize> & Into<u8>));
 /// ```
 ///
 /// Check that a type is [`Send`] but not [`Sync`].
@@ -338,6 +462,15 @@ macro_rules! assert_not_impl_any {
 /// assert_impl!(Cell<u32>: Send & !Sync);
 /// ```
 ///
+/// Check that a type is [`Copy`] only if it is [`Send`], and that it implements
+/// exactly one of two conversions:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_impl!(u8: Copy => Send);
+/// assert_impl!(u8: Into<u16> ^ Into<i8>);
+/// ```
+///
 /// Check simple one-off cases:
 ///
 /// ```
@@ -351,7 +484,17 @@ macro_rules! assert_not_impl_any {
 /// # #[macro_use] extern crate static_assertions; fn main() {}
 /// use std::rc::Rc;
 ///
-/// assert_impl!(for(T) Rc<T>: Clone);
+/// assert_impl!(for<T> Rc<T>: Clone);
+/// ```
+///
+/// Check that a type is [`Clone`] but never [`Copy`] when its parameter is
+/// [`Clone`]:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::rc::Rc;
+///
+/// assert_impl!(for<T> Rc<T>: Clone & !Copy where T: Clone);
 /// ```
 ///
 /// The following example fails to compile since `u64` cannot be converted into
@@ -362,21 +505,37 @@ macro_rules! assert_not_impl_any {
 /// assert_impl!(u64: (Into<u32>) | (Into<u16>));
 /// ```
 ///
+/// The following example fails to compile since `&&` is not an operator in
+/// trait expressions:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_impl!(u64: Copy && Clone);
+/// ```
+///
 /// [`bool`]: https://doc.rust-lang.org/std/primitive.bool.html
 /// [`Send`]: https://doc.rust-lang.org/std/marker/trait.Send.html
 /// [`Sync`]: https://doc.rust-lang.org/std/marker/trait.Sync.html
 /// [`Clone`]: https://doc.rust-lang.org/std/clone/trait.Clone.html
+/// [`Copy`]: https://doc.rust-lang.org/std/marker/trait.Copy.html
 #[macro_export(local_inner_macros)]
 macro_rules! assert_impl {
-    (for($($generic:tt)*) $ty:ty: $($rest:tt)*) => {
+    (for<$($rest:tt)*) => {
+        _for_generics!(assert_impl [] [] $($rest)*);
+    };
+    (for($($generic:tt)*) where($($bound:tt)*) $ty:ty: $($rest:tt)*) => {
         const _: () = {
-            fn assert_impl<$($generic)*>() {
+            #[allow(dead_code)]
+            fn assert_impl<$($generic)*>() where $($bound)* {
                 // Construct an expression using `True`/`False` and their
                 // operators, that corresponds to the provided expression.
                 let _: $crate::True = $crate::_does_impl!($ty: $($rest)*);
             }
         };
     };
+    (for($($generic:tt)*) $ty:ty: $($rest:tt)*) => {
+        assert_impl!(for($($generic)*) where() $ty: $($rest)*);
+    };
     ($ty:ty: $($rest:tt)*) => {
         // Construct an expression using `True`/`False` and their operators,
         // that corresponds to the provided expression.
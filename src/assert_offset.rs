@@ -0,0 +1,194 @@
+/// Asserts that the field is at the given byte offset within the type.
+///
+/// This is useful for [FFI] and on-disk formats, where other code relies on
+/// fields being at exact positions.
+///
+/// Nested fields are given as `a.b.c` and tuple fields by their index.
+///
+/// Related:
+/// - [`assert_offset_lt!`]
+/// - [`assert_offset_le!`]
+/// - [`assert_offset_gt!`]
+/// - [`assert_offset_ge!`]
+///
+/// # Examples
+///
+/// With `#[repr(C)]`, fields are laid out in declaration order:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     version: u16,
+///     flags: u16,
+///     len: u64,
+/// }
+///
+/// assert_offset_eq!(Header, magic, 0);
+/// assert_offset_eq!(Header, flags, 6);
+/// assert_offset_eq!(Header, len, 8);
+/// ```
+///
+/// Fields of nested types and tuples are also supported:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(C)]
+/// struct Point(u32, u32);
+///
+/// #[repr(C)]
+/// struct Line {
+///     start: Point,
+///     end: Point,
+/// }
+///
+/// assert_offset_eq!(Point, 1, 4);
+/// assert_offset_eq!(Line, end.1, 12);
+/// ```
+///
+/// The following example fails to compile because `len` is placed after
+/// 4 bytes of padding:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(C)]
+/// struct Packet {
+///     kind: u32,
+///     len: u64,
+/// }
+///
+/// assert_offset_eq!(Packet, len, 4);
+/// ```
+///
+/// This macro requires Rust 1.82 or later.
+///
+/// [FFI]: https://en.wikipedia.org/wiki/Foreign_function_interface
+/// [`assert_offset_lt!`]: macro.assert_offset_lt.html
+/// [`assert_offset_le!`]: macro.assert_offset_le.html
+/// [`assert_offset_gt!`]: macro.assert_offset_gt.html
+/// [`assert_offset_ge!`]: macro.assert_offset_ge.html
+#[cfg(offset_of)]
+#[macro_export]
+macro_rules! assert_offset_eq {
+    ($t:ty, $($field:tt).+, $offset:expr $(,)?) => {
+        $crate::_assert_offset!($t, [$($field).+], ==, "", $offset);
+    };
+}
+
+/// Asserts that the field is at a byte offset less than the given value.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(C)]
+/// struct Entry {
+///     hash: u64,
+///     key: [u8; 32],
+///     value: [u8; 256],
+/// }
+///
+/// // The key should always be within the first cache line.
+/// assert_offset_lt!(Entry, key, 64);
+/// ```
+///
+/// This macro requires Rust 1.82 or later.
+#[cfg(offset_of)]
+#[macro_export]
+macro_rules! assert_offset_lt {
+    ($t:ty, $($field:tt).+, $offset:expr $(,)?) => {
+        $crate::_assert_offset!($t, [$($field).+], <, "less than ", $offset);
+    };
+}
+
+/// Asserts that the field is at a byte offset less than or equal to the given
+/// value.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(C)]
+/// struct Frame {
+///     id: u16,
+///     data: [u8; 6],
+/// }
+///
+/// assert_offset_le!(Frame, data, 2);
+/// ```
+///
+/// This macro requires Rust 1.82 or later.
+#[cfg(offset_of)]
+#[macro_export]
+macro_rules! assert_offset_le {
+    ($t:ty, $($field:tt).+, $offset:expr $(,)?) => {
+        $crate::_assert_offset!($t, [$($field).+], <=, "at most ", $offset);
+    };
+}
+
+/// Asserts that the field is at a byte offset greater than the given value.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(C)]
+/// struct Frame {
+///     id: u16,
+///     data: [u8; 6],
+/// }
+///
+/// assert_offset_gt!(Frame, data, 0);
+/// ```
+///
+/// This macro requires Rust 1.82 or later.
+#[cfg(offset_of)]
+#[macro_export]
+macro_rules! assert_offset_gt {
+    ($t:ty, $($field:tt).+, $offset:expr $(,)?) => {
+        $crate::_assert_offset!($t, [$($field).+], >, "greater than ", $offset);
+    };
+}
+
+/// Asserts that the field is at a byte offset greater than or equal to the
+/// given value.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(C)]
+/// struct Frame {
+///     id: u16,
+///     data: [u8; 6],
+/// }
+///
+/// assert_offset_ge!(Frame, data, 2);
+/// ```
+///
+/// This macro requires Rust 1.82 or later.
+#[cfg(offset_of)]
+#[macro_export]
+macro_rules! assert_offset_ge {
+    ($t:ty, $($field:tt).+, $offset:expr $(,)?) => {
+        $crate::_assert_offset!($t, [$($field).+], >=, "at least ", $offset);
+    };
+}
+
+#[cfg(offset_of)]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_offset {
+    ($t:ty, [$($field:tt)+], $op:tt, $desc:tt, $offset:expr) => {
+        $crate::const_assert!(
+            $crate::_core::mem::offset_of!($t, $($field)+) $op $offset,
+            concat!(
+                "offset of `", stringify!($($field)+), "` in `",
+                stringify!($t), "` is {}, expected ", $desc, "{}"
+            ),
+            $crate::_core::mem::offset_of!($t, $($field)+),
+            $offset as usize,
+        );
+    };
+}
@@ -0,0 +1,87 @@
+/// Asserts that the struct has no padding bytes.
+///
+/// This is useful for types that are hashed or compared byte-wise, or copied
+/// to and from raw memory, where padding bytes would be uninitialized.
+///
+/// All fields of the struct must be listed, which is checked in the same way
+/// as with [`assert_fields_exact!`]. The size of the struct is then compared
+/// against the sum of the sizes of its fields. Generic structs are not
+/// supported.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     version: u16,
+///     flags: u16,
+///     len: u64,
+/// }
+///
+/// #[repr(C)]
+/// struct Rgba(u8, u8, u8, u8);
+///
+/// assert_no_padding!(Header: magic, version, flags, len);
+/// assert_no_padding!(Rgba: 0, 1, 2, 3);
+/// ```
+///
+/// Packed structs never have padding:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(C, packed)]
+/// struct Packet {
+///     kind: u8,
+///     len: u64,
+/// }
+///
+/// assert_no_padding!(Packet: kind, len);
+/// ```
+///
+/// The following example fails to compile because `len` is aligned to 8
+/// bytes, which leaves 4 bytes of padding after `kind`:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(C)]
+/// struct Packet {
+///     kind: u32,
+///     len: u64,
+/// }
+///
+/// assert_no_padding!(Packet: kind, len);
+/// ```
+///
+/// This macro requires Rust 1.64 or later.
+///
+/// [`assert_fields_exact!`]: macro.assert_fields_exact.html
+#[cfg(const_msg)]
+#[macro_export]
+macro_rules! assert_no_padding {
+    ($t:path: $($field:tt),+ $(,)?) => {
+        $crate::assert_fields_exact!($t: $($field),+);
+
+        const _: () = {
+            // Gets the size of a field via a function pointer that projects to
+            // it, since its type is not known. The projection goes through raw
+            // pointers, since fields of packed structs cannot be referenced.
+            // It is never called.
+            const fn size_of_field<T, F>(_: fn(*const T) -> *const F) -> usize {
+                $crate::_core::mem::size_of::<F>()
+            }
+
+            const SIZE: usize = $crate::_core::mem::size_of::<$t>();
+            const FIELDS: usize = 0 $(+ size_of_field(|value: *const $t| unsafe {
+                $crate::_core::ptr::addr_of!((*value).$field)
+            }))+;
+
+            $crate::const_assert_eq!(
+                SIZE, FIELDS;
+                concat!("`", stringify!($t), "` has {} bytes of padding"),
+                SIZE - FIELDS,
+            );
+        };
+    };
+}
@@ -20,23 +20,173 @@
 /// ```
 ///
 /// The following example fails to compile because `u32` has 4 times the size of
-/// `u8`:
+/// `u8`. On Rust 1.64 or later, the error reads
+/// ``assertion `size_of::<u32>() == size_of::<u8>()` failed: size_of::<u32>() =
+/// 4, size_of::<u8>() = 1``.
 ///
 /// ```compile_fail
 /// # #[macro_use] extern crate static_assertions; fn main() {}
 /// assert_size_eq!(u32, u8);
 /// ```
 ///
+/// Types depending on generic parameters can be compared for _all_ parameters
+/// satisfying some bounds by prefixing them with `for<...>`, optionally
+/// followed by a `where` clause at the end. This only works if the compiler
+/// can tell the sizes apart without knowing the parameters, such as with
+/// pointers. Failures are then reported by the compiler as a `transmute`
+/// between types of different sizes, along with both sizes in bits. Older
+/// compilers, such as Rust 1.64, also reject types given through an
+/// associated type of a parameter, like `<T as Trait>::Assoc`, as
+/// dependently-sized even when they are the same type.
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_size_eq!(for<T> Option<Box<T>>, Box<T>, usize);
+/// assert_size_eq!(for<'a, T> &'a T, &'a mut T where T: ?Sized + 'a);
+/// ```
+///
 /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
 /// [`u64`]: https://doc.rust-lang.org/std/primitive.u64.html
 /// [`u32`]: https://doc.rust-lang.org/std/primitive.u32.html
 #[macro_export]
 macro_rules! assert_size_eq {
-    ($x:ty, $($xs:ty),+ $(,)?) => {
-        const _: fn() = || {
-            $(let _ = $crate::_core::mem::transmute::<$x, $xs>;)+
+    (for<$($rest:tt)*) => {
+        $crate::_for_generics!(assert_size_eq [] [] $($rest)*);
+    };
+    (for($($generic:tt)*) where($($bound:tt)*) $x:ty, $($xs:ty),+ $(,)?) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_size_eq<$($generic)*>() where $($bound)* {
+                $(let _ = $crate::_core::mem::transmute::<$x, $xs>;)+
+            }
         };
     };
+    ($x:ty, $($xs:ty),+ $(,)?) => {
+        $($crate::_assert_layout_cmp!(size_of, ==, $x, $xs);)+
+    };
+}
+
+/// Asserts that the size of a type compares to a constant.
+///
+/// The comparison is written as `T == N`, where `N` is a constant [`usize`]
+/// expression, and may use `==`, `!=`, `<=`, or `>=`. Strict comparisons are
+/// not supported since `<` and `>` cannot be told apart from generic
+/// arguments.
+///
+/// On Rust 1.64 or later, failures report the actual size of the type.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(C, align(64))]
+/// struct Slot {
+///     seq: u64,
+///     data: [u8; 48],
+/// }
+///
+/// const CACHE_LINE: usize = 64;
+///
+/// assert_size!(Slot == 64);
+/// assert_size!(Slot <= CACHE_LINE);
+/// assert_size!(Option<Box<Slot>> == std::mem::size_of::<usize>());
+/// assert_size!([u8; 0] != 1);
+/// ```
+///
+/// The following example fails to compile, with an error saying that the size
+/// of `(u8, u32)` is 8:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_size!((u8, u32) == 5);
+/// ```
+///
+/// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+#[macro_export]
+macro_rules! assert_size {
+    ($($comparison:tt)+) => {
+        $crate::_assert_layout!(size_of, "size", [] $($comparison)+);
+    };
+}
+
+/// Asserts that types have a [niche] that makes wrapping them free.
+///
+/// `assert_niche!(T)` asserts that `Option<T>` is the same size as `T`, which
+/// is the case when `T` has invalid bit patterns (such as a null pointer) that
+/// can be used to represent `None`.
+///
+/// For wrappers other than [`Option`], `assert_niche!(T => W)` asserts that
+/// the wrapper type `W` is the same size as `T`.
+///
+/// Like [`assert_size_eq!`], types that depend on generic parameters can be
+/// checked by prefixing them with `for<...>`.
+///
+/// # Examples
+///
+/// References, [`NonNull`], [`Box`], and other types that cannot be null all
+/// have a niche:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::num::NonZeroU32;
+/// use std::ptr::NonNull;
+///
+/// struct Handle(NonNull<u8>);
+///
+/// assert_niche!(Handle, &'static str, NonZeroU32);
+/// assert_niche!(Handle => Result<Handle, ()>);
+/// assert_niche!(for<T> NonNull<T>);
+/// ```
+///
+/// The following example fails to compile because every bit pattern of a
+/// `u32` is a valid value:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_niche!(u32);
+/// ```
+///
+/// [niche]: https://rust-lang.github.io/unsafe-code-guidelines/glossary.html#niche
+/// [`Option`]: https://doc.rust-lang.org/std/option/enum.Option.html
+/// [`NonNull`]: https://doc.rust-lang.org/std/ptr/struct.NonNull.html
+/// [`Box`]: https://doc.rust-lang.org/std/boxed/struct.Box.html
+/// [`assert_size_eq!`]: macro.assert_size_eq.html
+#[macro_export]
+macro_rules! assert_niche {
+    (for<$($rest:tt)*) => {
+        $crate::_for_generics!(assert_niche [] [] $($rest)*);
+    };
+    (for $generic:tt where $bound:tt $($t:ty $(=> $w:ty)?),+ $(,)?) => {
+        $($crate::assert_size_eq!(
+            for $generic where $bound
+            $crate::_niche_wrapper!($t $(=> $w)?), $t
+        );)+
+    };
+    ($($t:ty $(=> $w:ty)?),+ $(,)?) => {
+        $($crate::_assert_niche!($t $(=> $w)?);)+
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_niche {
+    ($t:ty) => {
+        $crate::_assert_layout_cmp!(
+            size_of, ==, $crate::_core::option::Option<$t>, $t,
+            concat!("Option<", stringify!($t), ">"),
+            stringify!($t)
+        );
+    };
+    ($t:ty => $w:ty) => {
+        $crate::_assert_layout_cmp!(size_of, ==, $w, $t);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _niche_wrapper {
+    ($t:ty) => { $crate::_core::option::Option<$t> };
+    ($t:ty => $w:ty) => { $w };
 }
 
 /// Asserts that types are equal in alignment.
@@ -69,6 +219,12 @@ macro_rules! assert_eq_size {
 /// }
 /// ```
 ///
+/// Unlike [`assert_size_eq!`], failures are not reported with a custom message,
+/// since the types of the values may be generic or unnameable. The compiler's
+/// error about a `transmute` between types of different sizes names both types
+/// along with their sizes in bits instead, such as ``source type: `[u8; 16]`
+/// (128 bits)`` and ``target type: `[u8; 4]` (32 bits)`` below.
+///
 /// The following example fails to compile because byte arrays of different
 /// lengths have different sizes:
 ///
@@ -86,7 +242,10 @@ macro_rules! assert_eq_size {
 /// ];
 ///
 /// assert_size_eq_ptr!(BYTES, TABLE);
+/// # }
 /// ```
+///
+/// [`assert_size_eq!`]: macro.assert_size_eq.html
 #[macro_export]
 macro_rules! assert_size_eq_ptr {
     ($x:expr, $($xs:expr),+ $(,)?) => {
@@ -135,6 +294,10 @@ macro_rules! assert_eq_size_ptr {
 /// # }
 /// ```
 ///
+/// Like with [`assert_size_eq_ptr!`], failures are reported by the compiler as a
+/// `transmute` between types of different sizes, which names both types along
+/// with their sizes in bits.
+///
 /// Even though both values are 0, they are of types with different sizes:
 ///
 /// ```compile_fail
@@ -145,6 +308,7 @@ macro_rules! assert_eq_size_ptr {
 /// ```
 ///
 /// [`Clone`]: https://doc.rust-lang.org/std/clone/trait.Clone.html
+/// [`assert_size_eq_ptr!`]: macro.assert_size_eq_ptr.html
 #[macro_export(local_inner_macros)]
 macro_rules! assert_size_eq_val {
     ($x:expr, $($xs:expr),+ $(,)?) => {
@@ -166,3 +330,113 @@ macro_rules! assert_eq_size_val {
         assert_size_eq_val!($($t)*);
     };
 }
+
+/// Splits `T <op> N` at the comparison operator, which cannot otherwise be
+/// matched since it may not follow a type.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_layout {
+    (@check $f:ident, $name:tt, ($t:ty), $op:tt, $desc:tt, $value:expr) => {
+        $crate::const_assert!(
+            $crate::_core::mem::$f::<$t>() $op $value,
+            concat!($name, " of `{}` is {}, expected ", $desc, "{}"),
+            stringify!($t),
+            $crate::_core::mem::$f::<$t>(),
+            $value as usize,
+        );
+    };
+    ($f:ident, $name:tt, [$($t:tt)+] == $($value:tt)+) => {
+        $crate::_assert_layout!(@check $f, $name, ($($t)+), ==, "", $($value)+);
+    };
+    ($f:ident, $name:tt, [$($t:tt)+] != $($value:tt)+) => {
+        $crate::_assert_layout!(
+            @check $f, $name, ($($t)+), !=, "not ", $($value)+
+        );
+    };
+    ($f:ident, $name:tt, [$($t:tt)+] <= $($value:tt)+) => {
+        $crate::_assert_layout!(
+            @check $f, $name, ($($t)+), <=, "at most ", $($value)+
+        );
+    };
+    ($f:ident, $name:tt, [$($t:tt)+] >= $($value:tt)+) => {
+        $crate::_assert_layout!(
+            @check $f, $name, ($($t)+), >=, "at least ", $($value)+
+        );
+    };
+    ($f:ident, $name:tt, [$($t:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::_assert_layout!($f, $name, [$($t)* $next] $($rest)*);
+    };
+    ($f:ident, $name:tt, [$($t:tt)*]) => {
+        compile_error!(concat!(
+            "expected `", stringify!($($t)*),
+            "` to be followed by `==`, `!=`, `<=`, or `>=` and a constant"
+        ));
+    };
+}
+
+/// Compares the size or alignment of two types, reporting both values on
+/// failure.
+///
+/// Type names are passed as arguments rather than being part of the format
+/// string, since they may contain braces.
+#[cfg(const_msg)]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_layout_cmp {
+    ($f:ident, $op:tt, $x:ty, $y:ty) => {
+        $crate::_assert_layout_cmp!(
+            $f, $op, $x, $y, stringify!($x), stringify!($y)
+        );
+    };
+    ($f:ident, $op:tt, $x:ty, $y:ty, $x_name:expr, $y_name:expr) => {
+        $crate::const_assert!(
+            $crate::_core::mem::$f::<$x>() $op $crate::_core::mem::$f::<$y>(),
+            "assertion `{} {} {}` failed: {} = {}, {} = {}",
+            concat!(stringify!($f), "::<", $x_name, ">()"),
+            stringify!($op),
+            concat!(stringify!($f), "::<", $y_name, ">()"),
+            concat!(stringify!($f), "::<", $x_name, ">()"),
+            $crate::_core::mem::$f::<$x>(),
+            concat!(stringify!($f), "::<", $y_name, ">()"),
+            $crate::_core::mem::$f::<$y>(),
+        );
+    };
+}
+
+/// Compares the size or alignment of two types. Without custom messages,
+/// equality is checked in ways that make the compiler report both values.
+#[cfg(not(const_msg))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_layout_cmp {
+    (size_of, ==, $x:ty, $y:ty) => {
+        const _: fn() = || {
+            let _ = $crate::_core::mem::transmute::<$x, $y>;
+        };
+    };
+    ($f:ident, ==, $x:ty, $y:ty) => {
+        $crate::const_assert_eq_usize!(
+            $crate::_core::mem::$f::<$x>(),
+            $crate::_core::mem::$f::<$y>()
+        );
+    };
+    ($f:ident, $op:tt, $x:ty, $y:ty) => {
+        $crate::const_assert!(
+            $crate::_core::mem::$f::<$x>() $op $crate::_core::mem::$f::<$y>()
+        );
+    };
+    ($f:ident, $op:tt, $x:ty, $y:ty, $x_name:expr, $y_name:expr) => {
+        $crate::_assert_layout_cmp!($f, $op, $x, $y);
+    };
+}
+
+/// Compares the size or alignment of each type to the next.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_layout_chain {
+    ($f:ident, $op:tt, $x:ty) => {};
+    ($f:ident, $op:tt, $x:ty, $y:ty $(, $ys:ty)*) => {
+        $crate::_assert_layout_cmp!($f, $op, $x, $y);
+        $crate::_assert_layout_chain!($f, $op, $y $(, $ys)*);
+    };
+}
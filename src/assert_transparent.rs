@@ -0,0 +1,135 @@
+/// Asserts that a wrapper type has the same layout as the type it wraps.
+///
+/// This checks that both types have the same size and alignment, which is
+/// needed when [transmuting] between a newtype and its inner type or casting
+/// pointers to them. Passing `niche` as a third argument also checks that
+/// [`Option`] of either type has the same size, so that `Option<Wrapper>` and
+/// `Option<Inner>` can be transmuted between as well.
+///
+/// Unlike separate calls to [`assert_size_eq!`], [`assert_align_eq!`], and
+/// [`assert_niche!`], a failure is reported as a single error that lists every
+/// property of both types. On Rust 1.64 or later, the error reads, for example,
+/// ``assertion `Id` is transparent over `u64` failed: sizes are 16 and 8,
+/// alignments are 8 and 8``.
+///
+/// This does not check that the wrapper is `#[repr(transparent)]`, since types
+/// may happen to have the same layout otherwise.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::num::NonZeroU32;
+///
+/// #[repr(transparent)]
+/// struct Id(NonZeroU32);
+///
+/// #[repr(transparent)]
+/// struct Meters(f64);
+///
+/// assert_transparent!(Id, NonZeroU32, niche);
+/// assert_transparent!(Meters, f64);
+/// assert_transparent!(Meters, u64);
+/// ```
+///
+/// The following example fails to compile because `Id` has a tag in addition
+/// to the `u64` it wraps:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// struct Id(Option<u64>);
+///
+/// assert_transparent!(Id, u64);
+/// ```
+///
+/// The following example fails to compile because `Meters` has no [niche] for
+/// [`Option`] to use, unlike the reference it is compared with:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// #[repr(transparent)]
+/// struct Meters(usize);
+///
+/// assert_transparent!(Meters, &'static u8, niche);
+/// ```
+///
+/// [transmuting]: https://doc.rust-lang.org/std/mem/fn.transmute.html
+/// [niche]: https://doc.rust-lang.org/std/option/index.html#representation
+/// [`Option`]: https://doc.rust-lang.org/std/option/enum.Option.html
+/// [`assert_size_eq!`]: macro.assert_size_eq.html
+/// [`assert_align_eq!`]: macro.assert_align_eq.html
+/// [`assert_niche!`]: macro.assert_niche.html
+#[macro_export]
+macro_rules! assert_transparent {
+    ($w:ty, $i:ty, niche $(,)?) => {
+        $crate::_assert_transparent!($w, $i, niche);
+    };
+    ($w:ty, $i:ty $(,)?) => {
+        $crate::_assert_transparent!($w, $i);
+    };
+}
+
+#[cfg(const_msg)]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_transparent {
+    ($w:ty, $i:ty) => {
+        $crate::const_assert!(
+            $crate::_core::mem::size_of::<$w>()
+                == $crate::_core::mem::size_of::<$i>()
+                && $crate::_core::mem::align_of::<$w>()
+                    == $crate::_core::mem::align_of::<$i>(),
+            "assertion `{}` is transparent over `{}` failed: sizes are {} and \
+             {}, alignments are {} and {}",
+            stringify!($w),
+            stringify!($i),
+            $crate::_core::mem::size_of::<$w>(),
+            $crate::_core::mem::size_of::<$i>(),
+            $crate::_core::mem::align_of::<$w>(),
+            $crate::_core::mem::align_of::<$i>(),
+        );
+    };
+    ($w:ty, $i:ty, niche) => {
+        $crate::const_assert!(
+            $crate::_core::mem::size_of::<$w>()
+                == $crate::_core::mem::size_of::<$i>()
+                && $crate::_core::mem::align_of::<$w>()
+                    == $crate::_core::mem::align_of::<$i>()
+                && $crate::_core::mem::size_of::<
+                    $crate::_core::option::Option<$w>,
+                >() == $crate::_core::mem::size_of::<
+                    $crate::_core::option::Option<$i>,
+                >(),
+            "assertion `{}` is transparent over `{}` failed: sizes are {} and \
+             {}, alignments are {} and {}, `Option` sizes are {} and {}",
+            stringify!($w),
+            stringify!($i),
+            $crate::_core::mem::size_of::<$w>(),
+            $crate::_core::mem::size_of::<$i>(),
+            $crate::_core::mem::align_of::<$w>(),
+            $crate::_core::mem::align_of::<$i>(),
+            $crate::_core::mem::size_of::<$crate::_core::option::Option<$w>>(),
+            $crate::_core::mem::size_of::<$crate::_core::option::Option<$i>>(),
+        );
+    };
+}
+
+/// Without custom messages, each property is checked separately so that the
+/// compiler reports the values that differ.
+#[cfg(not(const_msg))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _assert_transparent {
+    ($w:ty, $i:ty) => {
+        $crate::_assert_layout_cmp!(size_of, ==, $w, $i);
+        $crate::_assert_layout_cmp!(align_of, ==, $w, $i);
+    };
+    ($w:ty, $i:ty, niche) => {
+        $crate::_assert_transparent!($w, $i);
+        $crate::_assert_layout_cmp!(
+            size_of, ==,
+            $crate::_core::option::Option<$w>,
+            $crate::_core::option::Option<$i>
+        );
+    };
+}
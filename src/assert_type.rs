@@ -41,28 +41,54 @@
 /// assert_type_eq_all!(str, String);
 /// ```
 ///
+/// Types depending on generic parameters can be compared for _all_ parameters
+/// satisfying some bounds by prefixing them with `for<...>`, optionally
+/// followed by a `where` clause at the end:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::ops::Deref;
+///
+/// assert_type_eq_all!(for<T: Iterator> <T as IntoIterator>::Item, T::Item);
+/// assert_type_eq_all!(for<T> <Box<T> as Deref>::Target, T where T: ?Sized);
+/// ```
+///
 /// [`c_float`]: https://doc.rust-lang.org/std/os/raw/type.c_float.html
 /// [`Deref`]: https://doc.rust-lang.org/std/ops/trait.Deref.html
 #[macro_export]
 macro_rules! assert_type_eq_all {
-    ($x:ty, $($xs:ty),+ $(,)*) => {
-        const _: fn() = || { $({
-            trait TypeEq {
-                type This: ?Sized;
-            }
+    (@check $x:ty, $($xs:ty),+) => {$({
+        trait TypeEq {
+            type This: ?Sized;
+        }
 
-            impl<T: ?Sized> TypeEq for T {
-                type This = Self;
-            }
+        impl<T: ?Sized> TypeEq for T {
+            type This = Self;
+        }
 
-            fn assert_type_eq_all<T, U>()
-            where
-                T: ?Sized + TypeEq<This = U>,
-                U: ?Sized,
-            {}
+        fn assert_type_eq_all<T, U>()
+        where
+            T: ?Sized + TypeEq<This = U>,
+            U: ?Sized,
+        {}
 
-            assert_type_eq_all::<$x, $xs>();
-        })+ };
+        assert_type_eq_all::<$x, $xs>();
+    })+};
+    (for<$($rest:tt)*) => {
+        $crate::_for_generics!(assert_type_eq_all [] [] $($rest)*);
+    };
+    (for($($generic:tt)*) where($($bound:tt)*) $x:ty, $($xs:ty),+ $(,)*) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_type_eq_all<$($generic)*>() where $($bound)* {
+                $crate::assert_type_eq_all!(@check $x, $($xs),+);
+            }
+        };
+    };
+    ($x:ty, $($xs:ty),+ $(,)*) => {
+        const _: fn() = || {
+            $crate::assert_type_eq_all!(@check $x, $($xs),+);
+        };
     };
 }
 
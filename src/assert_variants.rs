@@ -0,0 +1,105 @@
+/// Asserts that the `enum` has the given variants.
+///
+/// Variants are matched regardless of whether they are unit, tuple, or struct
+/// variants.
+///
+/// See [`assert_variants_exact!`] for also ensuring that no other variants
+/// exist.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// enum Color {
+///     Red,
+///     Green,
+///     Blue,
+///     Rgb(u8, u8, u8),
+///     Hsl { h: u16, s: u8, l: u8 },
+/// }
+///
+/// assert_variants!(Color: Red, Green, Blue);
+/// assert_variants!(Color: Rgb, Hsl);
+/// ```
+///
+/// Generic `enum` types are also supported:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// assert_variants!(Option<u8>: Some, None);
+/// assert_variants!(Result<(), ()>: Ok);
+/// ```
+///
+/// The following example fails to compile because [`Ordering`] does not have a
+/// `Same` variant:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::cmp::Ordering;
+///
+/// assert_variants!(Ordering: Less, Same, Greater);
+/// ```
+///
+/// [`assert_variants_exact!`]: macro.assert_variants_exact.html
+/// [`Ordering`]: https://doc.rust-lang.org/std/cmp/enum.Ordering.html
+#[macro_export]
+macro_rules! assert_variants {
+    ($t:ty: $($v:ident),+ $(,)?) => {
+        const _: fn() = || {
+            type Enum = $t;
+
+            #[allow(dead_code, unreachable_patterns)]
+            fn assert(value: Enum) {
+                match value {
+                    $(Enum::$v { .. } => {})+
+                    _ => {}
+                }
+            }
+        };
+    };
+}
+
+/// Asserts that the `enum` has _exactly_ the given variants.
+///
+/// This fails when a variant is added to or removed from the `enum`, which is
+/// useful for keeping hand-written tables and protocol definitions in sync.
+///
+/// # Examples
+///
+/// The order of variants does not matter:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::cmp::Ordering;
+///
+/// assert_variants_exact!(Ordering: Less, Greater, Equal);
+/// ```
+///
+/// The following example fails to compile because the `Green` variant is not
+/// listed:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// enum Color {
+///     Red,
+///     Green(u8),
+///     Blue { value: u8 },
+/// }
+///
+/// assert_variants_exact!(Color: Red, Blue);
+/// ```
+#[macro_export]
+macro_rules! assert_variants_exact {
+    ($t:ty: $($v:ident),+ $(,)?) => {
+        const _: fn() = || {
+            type Enum = $t;
+
+            #[allow(dead_code, unreachable_patterns)]
+            fn assert(value: Enum) {
+                match value {
+                    $(Enum::$v { .. } => {})+
+                }
+            }
+        };
+    };
+}
@@ -47,11 +47,49 @@
 /// const_assert!(5 * 5 == 5);
 /// ```
 ///
+/// # Custom Messages
+///
+/// Similar to [`assert!`], a message can be given after the expression to
+/// explain which invariant broke. Each `{}` in the message is replaced by the
+/// next argument, which must be a constant primitive integer or float,
+/// [`bool`], [`char`], or [`&str`]. Floats are shown via their bits, like
+/// `f32::from_bits(0x3fc00000)`.
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const MAX_PACKET: usize = 1500;
+/// const HEADER: usize = 64;
+/// const PAYLOAD: usize = 1472;
+///
+/// const_assert!(
+///     HEADER + PAYLOAD <= MAX_PACKET,
+///     "packets of {} bytes exceed the maximum of {}",
+///     HEADER + PAYLOAD,
+///     MAX_PACKET,
+/// );
+/// ```
+///
+/// This fails with:
+///
+/// ```txt
+/// error[E0080]: evaluation panicked: packets of 1536 bytes exceed the maximum of 1500
+/// ```
+///
+/// Messages require Rust 1.64 or later. On older compilers, they are ignored
+/// and the failure is reported as a type mismatch between `True` and `False`.
+///
 /// [static_assert]: http://en.cppreference.com/w/cpp/language/static_assert
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+/// [`bool`]: https://doc.rust-lang.org/std/primitive.bool.html
+/// [`char`]: https://doc.rust-lang.org/std/primitive.char.html
+/// [`&str`]: https://doc.rust-lang.org/std/primitive.str.html
 #[macro_export(local_inner_macros)]
 macro_rules! const_assert {
     ($x:expr $(,)?) => {
-        const _: $crate::True = _to_bool!($x);
+        _const_assert!($x);
+    };
+    ($x:expr, $msg:expr $(, $arg:expr)* $(,)?) => {
+        _const_assert!($x, $msg $(, $arg)*);
     };
 }
 
@@ -78,11 +116,25 @@ macro_rules! const_assert {
 /// # #[macro_use] extern crate static_assertions; fn main() {}
 /// const_assert_eq!(4 + 4, 4 * 4);
 /// ```
+///
+/// Because any number of constants can be compared, a
+/// [custom message](macro.const_assert.html#custom-messages) is separated from
+/// them by `;`:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const VERSION: u32 = 3;
+///
+/// const_assert_eq!(VERSION, 3; "protocol version changed to {}", VERSION);
+/// ```
 #[macro_export(local_inner_macros)]
 macro_rules! const_assert_eq {
     ($x:expr, $($y:expr),+ $(,)?) => {
         const_assert!($($x == $y)&&+);
     };
+    ($x:expr, $($y:expr),+; $($msg:tt)+) => {
+        const_assert!($($x == $y)&&+, $($msg)+);
+    };
 }
 
 /// Asserts that constants of type
@@ -90,7 +142,9 @@ macro_rules! const_assert_eq {
 /// value.
 ///
 /// This is equivalent to [`const_assert_eq!`](macro.const_assert_eq.html) but
-/// allows for inspecting the values in error messages.
+/// allows for inspecting the values in error messages. See
+/// [`const_assert_eq_typed!`](macro.const_assert_eq_typed.html) for other
+/// types, which requires Rust 1.64 or later.
 #[macro_export]
 macro_rules! const_assert_eq_usize {
     ($x:expr, $($y:expr),+ $(,)?) => {
@@ -99,6 +153,100 @@ macro_rules! const_assert_eq_usize {
     };
 }
 
+/// Asserts that constants of the given type are equal in value, showing both
+/// values on failure.
+///
+/// The type may be any primitive integer or float, [`bool`], [`char`],
+/// [`&str`], or `&[u8]`. Unlike with [`const_assert_eq!`], strings and byte
+/// slices can be compared even though `==` cannot be used on them in constant
+/// evaluation. Each value is first converted to the given type, so literals
+/// need no suffix and byte strings like `b"abc"` can be compared as `&[u8]`.
+///
+/// Floats are compared like with `==`, so NaN is not equal to anything and
+/// `-0.0` is equal to `0.0`. They are shown via their bits, such as
+/// `f64::from_bits(0x3ff8000000000000)` for `1.5`, which identify them
+/// exactly.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const MAGIC: &[u8] = b"\x7fELF";
+/// const PROTOCOL: &str = "wire/2";
+/// const PORT: u16 = 8080;
+///
+/// const_assert_eq_typed!(&[u8]: MAGIC, b"\x7fELF", &[0x7f, b'E', b'L', b'F']);
+/// const_assert_eq_typed!(&str: PROTOCOL, concat!("wire/", 2));
+/// const_assert_eq_typed!(u16: PORT, 8080);
+/// const_assert_eq_typed!(char: 'a', b'a' as char);
+/// const_assert_eq_typed!(f64: 3.0 / 2.0, 1.5);
+/// ```
+///
+/// The following example fails to compile because the protocol version was
+/// bumped. The error reads ``assertion `PROTOCOL == "wire/1"` failed: left =
+/// "wire/2", right = "wire/1"``.
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const PROTOCOL: &str = "wire/2";
+///
+/// const_assert_eq_typed!(&str: PROTOCOL, "wire/1");
+/// ```
+///
+/// As with [`const_assert_eq!`], a [custom message] can be given after `;`.
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const PORT: u16 = 8081;
+///
+/// const_assert_eq_typed!(u16: PORT, 8080; "port changed to {}", PORT);
+/// ```
+///
+/// This macro requires Rust 1.64 or later.
+///
+/// [`bool`]: https://doc.rust-lang.org/std/primitive.bool.html
+/// [`char`]: https://doc.rust-lang.org/std/primitive.char.html
+/// [`&str`]: https://doc.rust-lang.org/std/primitive.str.html
+/// [`const_assert_eq!`]: macro.const_assert_eq.html
+/// [custom message]: macro.const_assert.html#custom-messages
+#[cfg(const_msg)]
+#[macro_export]
+macro_rules! const_assert_eq_typed {
+    ($t:ty: $x:expr, $($y:expr),+ $(,)?) => {
+        $(const _: () = {
+            const X: $t = $x;
+            const Y: $t = $y;
+
+            $crate::_fmt::assert(
+                $crate::_fmt::Wrap(X).eq(Y),
+                "assertion `{} == {}` failed: left = {}, right = {}",
+                &[
+                    $crate::_fmt::Arg::Str(stringify!($x)),
+                    $crate::_fmt::Arg::Str(stringify!($y)),
+                    $crate::_fmt::Wrap(X).debug(),
+                    $crate::_fmt::Wrap(Y).debug(),
+                ],
+            )
+        };)+
+    };
+    ($t:ty: $x:expr, $($y:expr),+; $($msg:tt)+) => {
+        $crate::const_assert_eq_typed!(@msg [$($msg)+] $t: $x, $($y),+);
+    };
+    (@msg $msg:tt $t:ty: $x:expr, $($y:expr),+) => {
+        $(const _: () = {
+            const X: $t = $x;
+            const Y: $t = $y;
+
+            $crate::const_assert_eq_typed!(
+                @assert $crate::_fmt::Wrap(X).eq(Y), $msg
+            );
+        };)+
+    };
+    (@assert $x:expr, [$($msg:tt)+]) => {
+        $crate::const_assert!($x, $($msg)+);
+    };
+}
+
 /// Asserts that constants are **not** equal in value.
 ///
 /// # Examples
@@ -118,61 +266,467 @@ macro_rules! const_assert_eq_usize {
 /// # #[macro_use] extern crate static_assertions; fn main() {}
 /// const_assert_ne!(2 + 2, 2 * 2);
 /// ```
+///
+/// As with [`const_assert_eq!`](macro.const_assert_eq.html), a custom message
+/// can be given after `;`.
 #[macro_export(local_inner_macros)]
 macro_rules! const_assert_ne {
     ($x:expr, $($y:expr),+ $(,)?) => {
         const_assert!($($x != $y)&&+);
     };
+    ($x:expr, $($y:expr),+; $($msg:tt)+) => {
+        const_assert!($($x != $y)&&+, $($msg)+);
+    };
 }
 
 /// Asserts that constants are less than each other.
+///
+/// As with [`const_assert_eq!`](macro.const_assert_eq.html), a custom message
+/// can be given after `;`.
 #[macro_export(local_inner_macros)]
 macro_rules! const_assert_lt {
     ($x:expr, $($y:expr),+ $(,)?) => {
-        const_assert_lt!(@build $x, $($y),+);
+        const_assert_lt!(@build [] $x, $($y),+);
+    };
+    ($x:expr, $($y:expr),+; $($msg:tt)+) => {
+        const_assert_lt!(@build [, $($msg)+] $x, $($y),+);
     };
-    (@build $x:expr) => {};
-    (@build $x:expr, $($y:expr),+) => {
-        const_assert!($x < _head!($($y),+));
-        const_assert_lt!(@build $($y),+);
+    (@build [$($msg:tt)*] $x:expr) => {};
+    (@build [$($msg:tt)*] $x:expr, $y:expr $(, $ys:expr)*) => {
+        const_assert!($x < $y $($msg)*);
+        const_assert_lt!(@build [$($msg)*] $y $(, $ys)*);
     };
 }
 
 /// Asserts that constants are less than or equal to each other.
+///
+/// As with [`const_assert_eq!`](macro.const_assert_eq.html), a custom message
+/// can be given after `;`.
 #[macro_export(local_inner_macros)]
 macro_rules! const_assert_le {
     ($x:expr, $($y:expr),+ $(,)?) => {
-        const_assert_le!(@build $x, $($y),+);
+        const_assert_le!(@build [] $x, $($y),+);
     };
-    (@build $x:expr) => {};
-    (@build $x:expr, $($y:expr),+) => {
-        const_assert!($x <= _head!($($y),+));
-        const_assert_le!(@build $($y),+);
+    ($x:expr, $($y:expr),+; $($msg:tt)+) => {
+        const_assert_le!(@build [, $($msg)+] $x, $($y),+);
+    };
+    (@build [$($msg:tt)*] $x:expr) => {};
+    (@build [$($msg:tt)*] $x:expr, $y:expr $(, $ys:expr)*) => {
+        const_assert!($x <= $y $($msg)*);
+        const_assert_le!(@build [$($msg)*] $y $(, $ys)*);
     };
 }
 
 /// Asserts that constants are greater than each other.
+///
+/// As with [`const_assert_eq!`](macro.const_assert_eq.html), a custom message
+/// can be given after `;`.
 #[macro_export(local_inner_macros)]
 macro_rules! const_assert_gt {
     ($x:expr, $($y:expr),+ $(,)?) => {
-        const_assert_gt!(@build $x, $($y),+);
+        const_assert_gt!(@build [] $x, $($y),+);
+    };
+    ($x:expr, $($y:expr),+; $($msg:tt)+) => {
+        const_assert_gt!(@build [, $($msg)+] $x, $($y),+);
     };
-    (@build $x:expr) => {};
-    (@build $x:expr, $($y:expr),+) => {
-        const_assert!($x > _head!($($y),+));
-        const_assert_gt!(@build $($y),+);
+    (@build [$($msg:tt)*] $x:expr) => {};
+    (@build [$($msg:tt)*] $x:expr, $y:expr $(, $ys:expr)*) => {
+        const_assert!($x > $y $($msg)*);
+        const_assert_gt!(@build [$($msg)*] $y $(, $ys)*);
     };
 }
 
 /// Asserts that constants are less than or equal to each other.
+///
+/// As with [`const_assert_eq!`](macro.const_assert_eq.html), a custom message
+/// can be given after `;`.
 #[macro_export(local_inner_macros)]
 macro_rules! const_assert_ge {
     ($x:expr, $($y:expr),+ $(,)?) => {
-        const_assert_ge!(@build $x, $($y),+);
+        const_assert_ge!(@build [] $x, $($y),+);
+    };
+    ($x:expr, $($y:expr),+; $($msg:tt)+) => {
+        const_assert_ge!(@build [, $($msg)+] $x, $($y),+);
+    };
+    (@build [$($msg:tt)*] $x:expr) => {};
+    (@build [$($msg:tt)*] $x:expr, $y:expr $(, $ys:expr)*) => {
+        const_assert!($x >= $y $($msg)*);
+        const_assert_ge!(@build [$($msg)*] $y $(, $ys)*);
+    };
+}
+
+/// Asserts that a constant expression depending on generic parameters
+/// evaluates to `true`.
+///
+/// Unlike [`const_assert!`], this can refer to the generic parameters of an
+/// enclosing function or `impl`, and must be used where statements are
+/// allowed. The expression is only evaluated once the enclosing function is
+/// instantiated with concrete parameters, so it fails to compile wherever the
+/// function is used with parameters that do not satisfy it.
+///
+/// Such failures are only reported once code is generated for the function.
+/// This means that `cargo build` and `cargo test` report them, but `cargo
+/// check` does not.
+///
+/// The parameters that the expression refers to are listed before a `:`.
+/// Parameters that need bounds, such as to use a trait's associated constant,
+/// or `const` parameters are listed in `for<...>` instead, optionally followed
+/// by a `where` clause.
+///
+/// As with [`const_assert!`], a [custom message] can be given after the
+/// expression.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions;
+/// use std::mem::{align_of, size_of, MaybeUninit};
+///
+/// /// Stores values inline, which requires them to fit in 16 bytes.
+/// pub struct SmallBox<T> {
+///     bytes: MaybeUninit<[u64; 2]>,
+///     value: std::marker::PhantomData<T>,
+/// }
+///
+/// impl<T> SmallBox<T> {
+///     pub fn new(value: T) -> Self {
+///         const_assert_generic!(T: size_of::<T>() <= 16);
+///         const_assert_generic!(T: align_of::<T>() <= 8);
+///         // ...
+/// #       let _ = value;
+/// #       SmallBox { bytes: MaybeUninit::uninit(), value: Default::default() }
+///     }
+/// }
+///
+/// # fn main() {
+/// let _ = SmallBox::new(1u64);
+/// let _ = SmallBox::new([0u32; 4]);
+/// # }
+/// ```
+///
+/// Bounds and `const` parameters are given in `for<...>`:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// trait Packet {
+///     const LEN: usize;
+/// }
+///
+/// fn copy_to<P: Packet, const N: usize>(buffer: &mut [u8; N]) {
+///     const_assert_generic!(
+///         for<P: Packet, const N: usize> P::LEN <= N,
+///         "packet of {} bytes does not fit in {} bytes",
+///         P::LEN,
+///         N,
+///     );
+///     // ...
+/// #   let _ = buffer;
+/// }
+/// ```
+///
+/// The following example fails to compile because `SmallBox::new` is
+/// instantiated with a type of 32 bytes:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions;
+/// use std::mem::size_of;
+///
+/// pub struct SmallBox<T>(std::marker::PhantomData<T>);
+///
+/// impl<T> SmallBox<T> {
+///     pub fn new(value: T) -> Self {
+///         const_assert_generic!(
+///             T: size_of::<T>() <= 16,
+///             "values of {} bytes do not fit in a `SmallBox`",
+///             size_of::<T>(),
+///         );
+///         // ...
+/// #       let _ = value;
+/// #       SmallBox(Default::default())
+///     }
+/// }
+///
+/// # fn main() {
+/// let _ = SmallBox::new([0u64; 4]);
+/// # }
+/// ```
+///
+/// This macro requires Rust 1.64 or later.
+///
+/// [`const_assert!`]: macro.const_assert.html
+/// [custom message]: macro.const_assert.html#custom-messages
+#[cfg(const_msg)]
+#[macro_export]
+macro_rules! const_assert_generic {
+    (for<$($rest:tt)*) => {
+        $crate::_for_generics!(const_assert_generic [] [] $($rest)*);
+    };
+    (for($($generic:tt)*) where($($bound:tt)*) $($rest:tt)+) => {
+        $crate::_const_assert_generic!(
+            @param [$($generic)*] [$($bound)*] [] [] [$($rest)+] $($generic)*
+        );
+    };
+    ($($param:ident),+ : $($rest:tt)+) => {
+        $crate::const_assert_generic!(for($($param),+) where() $($rest)+);
+    };
+}
+
+/// Asserts that a constant array is sorted in ascending order.
+///
+/// This is useful for lookup tables that are searched via [`binary_search`],
+/// which silently returns wrong results if the table is not sorted. Equal
+/// elements are allowed next to each other; use [`const_assert_unique!`] to
+/// also rule out duplicates.
+///
+/// The elements may be any primitive integer, [`bool`], or [`char`]. Slices
+/// may be given instead of arrays. Integer literals in an array need a suffix
+/// on at least one element, such as `[1u8, 2]`.
+///
+/// Related:
+/// - [`const_assert_unique!`]
+/// - [`const_assert_len!`]
+/// - [`const_assert_contains!`]
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const PRIMES: [u16; 6] = [2, 3, 5, 7, 11, 13];
+/// const VOWELS: &[char] = &['a', 'e', 'i', 'o', 'u'];
+///
+/// const_assert_sorted!(PRIMES);
+/// const_assert_sorted!(VOWELS);
+/// const_assert_sorted!([-1i8, 0, 0, 1]);
+/// ```
+///
+/// The following example fails to compile with ``assertion `PRIMES` is sorted
+/// failed: 5 at index 3 is less than 7 at index 2``:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const PRIMES: [u16; 6] = [2, 3, 7, 5, 11, 13];
+///
+/// const_assert_sorted!(PRIMES);
+/// ```
+///
+/// A [custom message] can be given after `;`.
+///
+/// This macro requires Rust 1.64 or later.
+///
+/// [`binary_search`]: https://doc.rust-lang.org/std/primitive.slice.html#method.binary_search
+/// [`bool`]: https://doc.rust-lang.org/std/primitive.bool.html
+/// [`char`]: https://doc.rust-lang.org/std/primitive.char.html
+/// [`const_assert_unique!`]: macro.const_assert_unique.html
+/// [`const_assert_len!`]: macro.const_assert_len.html
+/// [`const_assert_contains!`]: macro.const_assert_contains.html
+/// [custom message]: macro.const_assert.html#custom-messages
+#[cfg(const_msg)]
+#[macro_export]
+macro_rules! const_assert_sorted {
+    ($table:expr $(,)?) => {
+        const _: () = {
+            const LEN: usize = $crate::_slice::Slice(&$table).sorted_len();
+
+            if LEN < $table.len() {
+                $crate::_fmt::fail(
+                    "assertion `{}` is sorted failed: {} at index {} is less than {} at index {}",
+                    &[
+                        $crate::_fmt::Arg::Str(stringify!($table)),
+                        $crate::_fmt::Wrap($table[LEN]).debug(),
+                        $crate::_fmt::Wrap(LEN).arg(),
+                        $crate::_fmt::Wrap($table[LEN - 1]).debug(),
+                        $crate::_fmt::Wrap(LEN - 1).arg(),
+                    ],
+                );
+            }
+        };
+    };
+    ($table:expr; $($msg:tt)+) => {
+        $crate::const_assert!(
+            $crate::_slice::Slice(&$table).sorted_len() == $table.len(),
+            $($msg)+
+        );
+    };
+}
+
+/// Asserts that a constant array has no duplicate elements.
+///
+/// The elements may be any primitive integer, [`bool`], or [`char`]. Slices
+/// may be given instead of arrays.
+///
+/// Sorted arrays are checked in linear time. Otherwise, each element is
+/// compared with all of those before it, which may exceed the limits of
+/// constant evaluation for arrays of thousands of elements.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const PORTS: [u16; 3] = [80, 443, 8080];
+///
+/// const_assert_unique!(PORTS);
+/// const_assert_unique!(['x', 'y', 'z']);
+/// ```
+///
+/// The following example fails to compile with ``assertion `PORTS` is unique
+/// failed: 80 at index 2 repeats index 0``:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const PORTS: [u16; 3] = [80, 443, 80];
+///
+/// const_assert_unique!(PORTS);
+/// ```
+///
+/// A [custom message] can be given after `;`.
+///
+/// This macro requires Rust 1.64 or later.
+///
+/// [`bool`]: https://doc.rust-lang.org/std/primitive.bool.html
+/// [`char`]: https://doc.rust-lang.org/std/primitive.char.html
+/// [custom message]: macro.const_assert.html#custom-messages
+#[cfg(const_msg)]
+#[macro_export]
+macro_rules! const_assert_unique {
+    ($table:expr $(,)?) => {
+        const _: () = {
+            const LEN: usize = $crate::_slice::Slice(&$table).unique_len();
+
+            if LEN < $table.len() {
+                $crate::_fmt::fail(
+                    "assertion `{}` is unique failed: {} at index {} repeats index {}",
+                    &[
+                        $crate::_fmt::Arg::Str(stringify!($table)),
+                        $crate::_fmt::Wrap($table[LEN]).debug(),
+                        $crate::_fmt::Wrap(LEN).arg(),
+                        $crate::_fmt::Wrap(
+                            $crate::_slice::Slice(&$table).position($table[LEN]),
+                        )
+                        .arg(),
+                    ],
+                );
+            }
+        };
+    };
+    ($table:expr; $($msg:tt)+) => {
+        $crate::const_assert!(
+            $crate::_slice::Slice(&$table).unique_len() == $table.len(),
+            $($msg)+
+        );
+    };
+}
+
+/// Asserts that a constant array has the given length.
+///
+/// This is mostly useful for slices, as well as arrays whose length is given
+/// by another constant. Unlike the other array assertions, the elements may
+/// be of any type.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const LOOKUP: &[u8] = &[0; 256];
+///
+/// const_assert_len!(LOOKUP, 256);
+/// const_assert_len!(["a", "b"], 2);
+/// ```
+///
+/// The following example fails to compile with ``length of `NAMES` is 3,
+/// expected 4``:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const NAMES: &[&str] = &["red", "green", "blue"];
+///
+/// const_assert_len!(NAMES, 4);
+/// ```
+///
+/// A [custom message] can be given after `;`.
+///
+/// This macro requires Rust 1.64 or later.
+///
+/// [custom message]: macro.const_assert.html#custom-messages
+#[cfg(const_msg)]
+#[macro_export]
+macro_rules! const_assert_len {
+    ($table:expr, $len:expr $(,)?) => {
+        const _: () = {
+            const LEN: usize = $len;
+
+            $crate::const_assert!(
+                $table.len() == LEN,
+                "length of `{}` is {}, expected {}",
+                stringify!($table),
+                $table.len(),
+                LEN,
+            );
+        };
+    };
+    ($table:expr, $len:expr; $($msg:tt)+) => {
+        const _: () = {
+            const LEN: usize = $len;
+
+            $crate::const_assert!($table.len() == LEN, $($msg)+);
+        };
+    };
+}
+
+/// Asserts that a constant array contains the given value.
+///
+/// The elements may be any primitive integer, [`bool`], or [`char`]. Slices
+/// may be given instead of arrays.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const DEFAULT_PORT: u16 = 443;
+/// const PORTS: [u16; 3] = [80, 443, 8080];
+///
+/// const_assert_contains!(PORTS, DEFAULT_PORT);
+/// const_assert_contains!(PORTS, 8000 + 80);
+/// ```
+///
+/// The following example fails to compile with ``assertion `PORTS` contains
+/// 22 failed``:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const PORTS: [u16; 3] = [80, 443, 8080];
+///
+/// const_assert_contains!(PORTS, 22);
+/// ```
+///
+/// A [custom message] can be given after `;`.
+///
+/// This macro requires Rust 1.64 or later.
+///
+/// [`bool`]: https://doc.rust-lang.org/std/primitive.bool.html
+/// [`char`]: https://doc.rust-lang.org/std/primitive.char.html
+/// [custom message]: macro.const_assert.html#custom-messages
+#[cfg(const_msg)]
+#[macro_export]
+macro_rules! const_assert_contains {
+    ($table:expr, $x:expr $(,)?) => {
+        const _: () = {
+            let x = $x;
+
+            if $crate::_slice::Slice(&$table).position(x) == $table.len() {
+                $crate::_fmt::fail(
+                    "assertion `{}` contains {} failed",
+                    &[
+                        $crate::_fmt::Arg::Str(stringify!($table)),
+                        $crate::_fmt::Wrap(x).debug(),
+                    ],
+                );
+            }
+        };
     };
-    (@build $x:expr) => {};
-    (@build $x:expr, $($y:expr),+) => {
-        const_assert!($x >= _head!($($y),+));
-        const_assert_ge!(@build $($y),+);
+    ($table:expr, $x:expr; $($msg:tt)+) => {
+        $crate::const_assert!(
+            $crate::_slice::Slice(&$table).position($x) < $table.len(),
+            $($msg)+
+        );
     };
 }
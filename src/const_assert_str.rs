@@ -0,0 +1,206 @@
+/// Asserts that string constants are equal.
+///
+/// This is a shorthand for [`const_assert_eq_typed!`] with [`&str`], which
+/// shows both strings on failure.
+///
+/// Related:
+/// - [`const_assert_str_starts_with!`]
+/// - [`const_assert_str_len!`]
+/// - [`const_assert_str_ascii!`]
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const NAME: &str = concat!("static", "_assertions");
+///
+/// const_assert_str_eq!(NAME, "static_assertions");
+/// ```
+///
+/// The following example fails to compile with ``assertion `GREETING ==
+/// "hello"` failed: left = "hallo", right = "hello"``:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const GREETING: &str = "hallo";
+///
+/// const_assert_str_eq!(GREETING, "hello");
+/// ```
+///
+/// As with [`const_assert_eq!`], a [custom message] can be given after `;`.
+///
+/// This macro requires Rust 1.64 or later.
+///
+/// [`&str`]: https://doc.rust-lang.org/std/primitive.str.html
+/// [`const_assert_eq!`]: macro.const_assert_eq.html
+/// [`const_assert_eq_typed!`]: macro.const_assert_eq_typed.html
+/// [`const_assert_str_starts_with!`]: macro.const_assert_str_starts_with.html
+/// [`const_assert_str_len!`]: macro.const_assert_str_len.html
+/// [`const_assert_str_ascii!`]: macro.const_assert_str_ascii.html
+/// [custom message]: macro.const_assert.html#custom-messages
+#[cfg(const_msg)]
+#[macro_export]
+macro_rules! const_assert_str_eq {
+    ($($tokens:tt)+) => {
+        $crate::const_assert_eq_typed!(&str: $($tokens)+);
+    };
+}
+
+/// Asserts that a string constant starts with the given prefix.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const PROTOCOL: &str = "wire/2.1";
+///
+/// const_assert_str_starts_with!(PROTOCOL, "wire/2.");
+/// ```
+///
+/// The following example fails to compile with ``assertion `PROTOCOL` starts
+/// with "wire/2." failed: value is "wire/3.0"``:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const PROTOCOL: &str = "wire/3.0";
+///
+/// const_assert_str_starts_with!(PROTOCOL, "wire/2.");
+/// ```
+///
+/// A [custom message] can be given after `;`.
+///
+/// This macro requires Rust 1.64 or later.
+///
+/// [custom message]: macro.const_assert.html#custom-messages
+#[cfg(const_msg)]
+#[macro_export]
+macro_rules! const_assert_str_starts_with {
+    ($s:expr, $prefix:expr $(,)?) => {
+        const _: () = {
+            const S: &str = $s;
+            const PREFIX: &str = $prefix;
+
+            $crate::const_assert!(
+                $crate::_str::starts_with(S, PREFIX),
+                "assertion `{}` starts with {} failed: value is {}",
+                stringify!($s),
+                $crate::_fmt::Wrap(PREFIX).debug(),
+                $crate::_fmt::Wrap(S).debug(),
+            );
+        };
+    };
+    ($s:expr, $prefix:expr; $($msg:tt)+) => {
+        const _: () = {
+            const S: &str = $s;
+            const PREFIX: &str = $prefix;
+
+            $crate::const_assert!($crate::_str::starts_with(S, PREFIX), $($msg)+);
+        };
+    };
+}
+
+/// Asserts that a string constant has the given length in bytes.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const COUNTRY: &str = "NZ";
+/// const CURRENCY: &str = "€";
+///
+/// const_assert_str_len!(COUNTRY, 2);
+/// const_assert_str_len!(CURRENCY, 3);
+/// ```
+///
+/// The following example fails to compile with ``length of `CODE` is 4,
+/// expected 3``:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const CODE: &str = "EURO";
+///
+/// const_assert_str_len!(CODE, 3);
+/// ```
+///
+/// A [custom message] can be given after `;`.
+///
+/// This macro requires Rust 1.64 or later.
+///
+/// [custom message]: macro.const_assert.html#custom-messages
+#[cfg(const_msg)]
+#[macro_export]
+macro_rules! const_assert_str_len {
+    ($s:expr, $len:expr $(,)?) => {
+        const _: () = {
+            const S: &str = $s;
+            const LEN: usize = $len;
+
+            $crate::const_assert!(
+                S.len() == LEN,
+                "length of `{}` is {}, expected {}",
+                stringify!($s),
+                S.len(),
+                LEN,
+            );
+        };
+    };
+    ($s:expr, $len:expr; $($msg:tt)+) => {
+        const _: () = {
+            const S: &str = $s;
+            const LEN: usize = $len;
+
+            $crate::const_assert!(S.len() == LEN, $($msg)+);
+        };
+    };
+}
+
+/// Asserts that a string constant only contains ASCII characters.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const HEADER: &str = "X-Request-Id";
+///
+/// const_assert_str_ascii!(HEADER);
+/// const_assert_str_ascii!("");
+/// ```
+///
+/// The following example fails to compile with ``assertion `HEADER` is ASCII
+/// failed: non-ASCII byte at index 9``:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// const HEADER: &str = "X-Request—Id";
+///
+/// const_assert_str_ascii!(HEADER);
+/// ```
+///
+/// A [custom message] can be given after `;`.
+///
+/// This macro requires Rust 1.64 or later.
+///
+/// [custom message]: macro.const_assert.html#custom-messages
+#[cfg(const_msg)]
+#[macro_export]
+macro_rules! const_assert_str_ascii {
+    ($s:expr $(,)?) => {
+        const _: () = {
+            const S: &str = $s;
+
+            $crate::const_assert!(
+                $crate::_str::ascii_len(S) == S.len(),
+                "assertion `{}` is ASCII failed: non-ASCII byte at index {}",
+                stringify!($s),
+                $crate::_str::ascii_len(S),
+            );
+        };
+    };
+    ($s:expr; $($msg:tt)+) => {
+        const _: () = {
+            const S: &str = $s;
+
+            $crate::const_assert!($crate::_str::ascii_len(S) == S.len(), $($msg)+);
+        };
+    };
+}
@@ -1,5 +1,8 @@
 /// Returns `true` if the type does implement a logical trait expression.
 ///
+/// The trait expression has the same grammar as in [`assert_impl!`], with `!`,
+/// `&`, `+`, `^`, `|` and `=>` operators.
+///
 /// # Examples
 ///
 /// One can mimic `assert_impl!` using this macro:
@@ -10,6 +13,21 @@
 ///
 /// const_assert!(CONDITION);
 /// ```
+///
+/// The result is a `bool` that can be used in any constant expression:
+///
+/// ```
+/// # #[macro_use] extern crate static_assertions; fn main() {}
+/// use std::cell::Cell;
+///
+/// const THREAD_LOCAL: bool = does_impl!(Cell<u8>: Send & !Sync);
+/// const SOUND: bool = does_impl!(Cell<u8>: Sync => Send);
+///
+/// const_assert!(THREAD_LOCAL && SOUND);
+/// const_assert!(!does_impl!(u8: Copy ^ Clone));
+/// ```
+///
+/// [`assert_impl!`]: macro.assert_impl.html
 #[macro_export(local_inner_macros)]
 macro_rules! does_impl {
     ($ty:ty: $($trait_expr:tt)+) => {
@@ -21,11 +39,18 @@ macro_rules! does_impl {
 /// given trait boolean expression. Can be used in const contexts if it doesn't
 /// depend on outer generic parameters.
 ///
-/// This is the core of `assert_impl`.
+/// This is the core of `assert_impl`. See its documentation for the grammar.
 #[doc(hidden)]
-#[macro_export(local_inner_macros)]
+#[macro_export]
 macro_rules! _does_impl {
-    ($ty:ty: $($rest:tt)*) => {{
+    // Checks the type of a local variable instead, which may be unnameable,
+    // such as the future returned by an `async fn`. This must be used where
+    // statements are allowed.
+    (@value $value:ident: $($rest:tt)*) => {{
+        $crate::_does_impl!(@setup);
+        *$crate::_does_impl!(@expr(@value $value,) $($rest)*)
+    }};
+    (@setup) => {
         #[allow(unused_imports)]
         use $crate::{
             _bool::{True, False},
@@ -34,63 +59,207 @@ macro_rules! _does_impl {
 
         // Fallback trait that returns false if the type does not implement a
         // given trait.
+        #[allow(dead_code)]
         trait DoesntImpl {
             const DOES_IMPL: False = False;
+
+            fn does_impl(&self) -> &'static False {
+                &False
+            }
         }
         impl<T: ?Sized> DoesntImpl for T {}
+    };
+
+    ($ty:ty: $($rest:tt)*) => {{
+        $crate::_does_impl!(@setup);
 
         // Construct an expression using `True`/`False` and their operators,
         // that corresponds to the provided expression.
-        *_does_impl!(@boolexpr($ty,) $($rest)*)
+        *$crate::_does_impl!(@expr($ty,) $($rest)*)
     }};
 
-    (@boolexpr($($args:tt)*) ($($expr:tt)*)) => {
-        _does_impl!(@boolexpr($($args)*) $($expr)*)
+    // Single tokens skip straight to the unary operators.
+    (@expr $args:tt $expr:tt) => {
+        $crate::_does_impl!(@unary $args $expr)
     };
-    (@boolexpr($($args:tt)*) !($($expr:tt)*)) => {
-        _does_impl!(@boolexpr($($args)*) $($expr)*).not()
+    (@expr $args:tt $($expr:tt)*) => {
+        $crate::_does_impl!(@scan $args () [] [] [] $($expr)*)
     };
-    (@boolexpr($($args:tt)*) ($($left:tt)*) | $($right:tt)*) => {{
-        let left = _does_impl!(@boolexpr($($args)*) $($left)*);
-        let right = _does_impl!(@boolexpr($($args)*) $($right)*);
-        left.or(right)
-    }};
-    (@boolexpr($($args:tt)*) ($($left:tt)*) & $($right:tt)*) => {{
-        let left = _does_impl!(@boolexpr($($args)*) $($left)*);
-        let right = _does_impl!(@boolexpr($($args)*) $($right)*);
-        left.and(right)
-    }};
-    (@boolexpr($($args:tt)*) !($($left:tt)*) | $($right:tt)*) => {{
-        _does_impl!(@boolexpr($($args)*) (!($($left)*)) | $($right)*)
-    }};
-    (@boolexpr($($args:tt)*) !($($left:tt)*) & $($right:tt)*) => {{
-        _does_impl!(@boolexpr($($args)*) (!($($left)*)) & $($right)*)
-    }};
-    (@boolexpr($($args:tt)*) !$left:ident | $($right:tt)*) => {{
-        _does_impl!(@boolexpr($($args)*) !($left) | $($right)*)
+
+    // Splits the expression into operands at binary operators outside of
+    // generic arguments, in a single pass. The state is the operator seen so
+    // far, the finished operands, the current operand, and the nesting of
+    // generic arguments as tracked by `_track_generics!`, so that traits like
+    // `AsRef<&str>` need not be parenthesized.
+    //
+    // Once a different operator follows, the rest of the expression becomes
+    // the last operand, so that `x & y | z` is parsed as `x & (y | z)` like in
+    // 1.1.0. Operands of a single token or a path with at most one simple
+    // generic argument are taken along with the following operator, so that
+    // long expressions stay within the recursion limit.
+    (@scan $args:tt ($(&)?) [$($o:tt)*] [] [] $x:tt & $($rest:tt)*) => {
+        $crate::_does_impl!(@scan $args (&) [$($o)* [$x]] [] [] $($rest)*)
+    };
+    (@scan $args:tt ($(&)?) [$($o:tt)*] [] [] $($x:ident)::+ & $($rest:tt)*) => {
+        $crate::_does_impl!(@scan $args (&) [$($o)* [$($x)::+]] [] [] $($rest)*)
+    };
+    (@scan $args:tt ($(&)?) [$($o:tt)*] [] [] $($x:ident)::+ <$g:tt> & $($rest:tt)*) => {
+        $crate::_does_impl!(@scan $args (&) [$($o)* [$($x)::+ <$g>]] [] [] $($rest)*)
+    };
+    (@scan $args:tt ($(&)?) [$($o:tt)*] [$($cur:tt)*] [] & $($rest:tt)*) => {
+        $crate::_does_impl!(@scan $args (&) [$($o)* [$($cur)*]] [] [] $($rest)*)
+    };
+    (@scan $args:tt ($(&)?) [$($o:tt)*] [] [] $x:tt + $($rest:tt)*) => {
+        $crate::_does_impl!(@scan $args (&) [$($o)* [$x]] [] [] $($rest)*)
+    };
+    (@scan $args:tt ($(&)?) [$($o:tt)*] [] [] $($x:ident)::+ + $($rest:tt)*) => {
+        $crate::_does_impl!(@scan $args (&) [$($o)* [$($x)::+]] [] [] $($rest)*)
+    };
+    (@scan $args:tt ($(&)?) [$($o:tt)*] [] [] $($x:ident)::+ <$g:tt> + $($rest:tt)*) => {
+        $crate::_does_impl!(@scan $args (&) [$($o)* [$($x)::+ <$g>]] [] [] $($rest)*)
+    };
+    (@scan $args:tt ($(&)?) [$($o:tt)*] [$($cur:tt)*] [] + $($rest:tt)*) => {
+        $crate::_does_impl!(@scan $args (&) [$($o)* [$($cur)*]] [] [] $($rest)*)
+    };
+    (@scan $args:tt ($(|)?) [$($o:tt)*] [] [] $x:tt | $($rest:tt)*) => {
+        $crate::_does_impl!(@scan $args (|) [$($o)* [$x]] [] [] $($rest)*)
+    };
+    (@scan $args:tt ($(|)?) [$($o:tt)*] [] [] $($x:ident)::+ | $($rest:tt)*) => {
+        $crate::_does_impl!(@scan $args (|) [$($o)* [$($x)::+]] [] [] $($rest)*)
+    };
+    (@scan $args:tt ($(|)?) [$($o:tt)*] [] [] $($x:ident)::+ <$g:tt> | $($rest:tt)*) => {
+        $crate::_does_impl!(@scan $args (|) [$($o)* [$($x)::+ <$g>]] [] [] $($rest)*)
+    };
+    (@scan $args:tt ($(|)?) [$($o:tt)*] [$($cur:tt)*] [] | $($rest:tt)*) => {
+        $crate::_does_impl!(@scan $args (|) [$($o)* [$($cur)*]] [] [] $($rest)*)
+    };
+    (@scan $args:tt ($(^)?) [$($o:tt)*] [] [] $x:tt ^ $($rest:tt)*) => {
+        $crate::_does_impl!(@scan $args (^) [$($o)* [$x]] [] [] $($rest)*)
+    };
+    (@scan $args:tt ($(^)?) [$($o:tt)*] [] [] $($x:ident)::+ ^ $($rest:tt)*) => {
+        $crate::_does_impl!(@scan $args (^) [$($o)* [$($x)::+]] [] [] $($rest)*)
+    };
+    (@scan $args:tt ($(^)?) [$($o:tt)*] [] [] $($x:ident)::+ <$g:tt> ^ $($rest:tt)*) => {
+        $crate::_does_impl!(@scan $args (^) [$($o)* [$($x)::+ <$g>]] [] [] $($rest)*)
+    };
+    (@scan $args:tt ($(^)?) [$($o:tt)*] [$($cur:tt)*] [] ^ $($rest:tt)*) => {
+        $crate::_does_impl!(@scan $args (^) [$($o)* [$($cur)*]] [] [] $($rest)*)
+    };
+    (@scan $args:tt ($(=>)?) [$($o:tt)*] [] [] $x:tt => $($rest:tt)*) => {
+        $crate::_does_impl!(@scan $args (=>) [$($o)* [$x]] [] [] $($rest)*)
+    };
+    (@scan $args:tt ($(=>)?) [$($o:tt)*] [] [] $($x:ident)::+ => $($rest:tt)*) => {
+        $crate::_does_impl!(@scan $args (=>) [$($o)* [$($x)::+]] [] [] $($rest)*)
+    };
+    (@scan $args:tt ($(=>)?) [$($o:tt)*] [] [] $($x:ident)::+ <$g:tt> => $($rest:tt)*) => {
+        $crate::_does_impl!(@scan $args (=>) [$($o)* [$($x)::+ <$g>]] [] [] $($rest)*)
+    };
+    (@scan $args:tt ($(=>)?) [$($o:tt)*] [$($cur:tt)*] [] => $($rest:tt)*) => {
+        $crate::_does_impl!(@scan $args (=>) [$($o)* [$($cur)*]] [] [] $($rest)*)
+    };
+    (@scan $args:tt $op:tt [$($o:tt)*] [$($cur:tt)*] [] & $($rest:tt)*) => {
+        $crate::_does_impl!(@fold $args $op [$($o)* [($($cur)* & $($rest)*)]])
+    };
+    (@scan $args:tt $op:tt [$($o:tt)*] [$($cur:tt)*] [] + $($rest:tt)*) => {
+        $crate::_does_impl!(@fold $args $op [$($o)* [($($cur)* + $($rest)*)]])
+    };
+    (@scan $args:tt $op:tt [$($o:tt)*] [$($cur:tt)*] [] | $($rest:tt)*) => {
+        $crate::_does_impl!(@fold $args $op [$($o)* [($($cur)* | $($rest)*)]])
+    };
+    (@scan $args:tt $op:tt [$($o:tt)*] [$($cur:tt)*] [] ^ $($rest:tt)*) => {
+        $crate::_does_impl!(@fold $args $op [$($o)* [($($cur)* ^ $($rest)*)]])
+    };
+    (@scan $args:tt $op:tt [$($o:tt)*] [$($cur:tt)*] [] => $($rest:tt)*) => {
+        $crate::_does_impl!(@fold $args $op [$($o)* [($($cur)* => $($rest)*)]])
+    };
+    (@scan $args:tt $op:tt $o:tt $cur:tt [] && $($rest:tt)*) => {
+        compile_error!("expected `&`, found `&&`")
+    };
+    (@scan $args:tt $op:tt $o:tt $cur:tt [] || $($rest:tt)*) => {
+        compile_error!("expected `|`, found `||`")
+    };
+    (@scan $args:tt $op:tt $o:tt $cur:tt $depth:tt $($rest:tt)+) => {
+        $crate::_track_generics!(_does_impl [@scan $args $op $o] $cur $depth $($rest)+)
+    };
+    (@scan $args:tt $op:tt [$($o:tt)*] [$($cur:tt)*] []) => {
+        $crate::_does_impl!(@fold $args $op [$($o)* [$($cur)*]])
+    };
+    (@scan $args:tt $op:tt $o:tt [$($cur:tt)*] [$($depth:tt)+]) => {
+        compile_error!(concat!(
+            "unclosed generic arguments in trait expression `",
+            stringify!($($cur)*),
+            "`"
+        ))
+    };
+
+    // Combines the operands with the operator. `=>` is right-associative,
+    // while the others are associative.
+    (@fold $args:tt $op:tt [[$($x:tt)*]]) => {
+        $crate::_does_impl!(@unary $args $($x)*)
+    };
+    (@fold $args:tt (&) [[$($x:tt)*] $([$($xs:tt)*])+]) => {
+        $crate::_does_impl!(@unary $args $($x)*)
+            $(.and($crate::_does_impl!(@unary $args $($xs)*)))+
+    };
+    (@fold $args:tt (|) [[$($x:tt)*] $([$($xs:tt)*])+]) => {
+        $crate::_does_impl!(@unary $args $($x)*)
+            $(.or($crate::_does_impl!(@unary $args $($xs)*)))+
+    };
+    (@fold $args:tt (^) [[$($x:tt)*] $([$($xs:tt)*])+]) => {{
+        let value = $crate::_does_impl!(@unary $args $($x)*);
+        $(
+            let right = $crate::_does_impl!(@unary $args $($xs)*);
+            let value = value.and(right.not()).or(value.not().and(right));
+        )+
+        value
     }};
-    (@boolexpr($($args:tt)*) !$left:ident & $($right:tt)*) => {{
-        _does_impl!(@boolexpr($($args)*) !($left) & $($right)*)
+    (@fold $args:tt (=>) [[$($x:tt)*] $($xs:tt)+]) => {{
+        let left = $crate::_does_impl!(@unary $args $($x)*);
+        let right = $crate::_does_impl!(@fold $args (=>) [$($xs)+]);
+        left.not().or(right)
     }};
-    (@boolexpr($($args:tt)*) $left:ident | $($right:tt)*) => {
-        _does_impl!(@boolexpr($($args)*) ($left) | $($right)*)
+
+    (@unary($($args:tt)*) !$($expr:tt)+) => {
+        $crate::_does_impl!(@unary($($args)*) $($expr)+).not()
     };
-    (@boolexpr($($args:tt)*) $left:ident & $($right:tt)*) => {{
-        _does_impl!(@boolexpr($($args)*) ($left) & $($right)*)
-    }};
-    (@boolexpr($($args:tt)*) !$expr:ident) => {
-        _does_impl!(@boolexpr($($args)*) !($expr))
+    (@unary($($args:tt)*) ($($expr:tt)+)) => {
+        $crate::_does_impl!(@expr($($args)*) $($expr)+)
     };
-    (@boolexpr($($args:tt)*) !$expr:path) => {
-        _does_impl!(@boolexpr($($args)*) !($expr))
+    (@unary($($args:tt)*) $trait:path) => {
+        $crate::_does_impl!(@base($($args)*) $trait)
     };
-    (@boolexpr($($args:tt)*) $expr:ident) => {
-        _does_impl!(@base($($args)*) $expr)
+    (@unary($($args:tt)*) $lifetime:lifetime) => {
+        $crate::_does_impl!(@base($($args)*) $lifetime)
     };
-    (@boolexpr($($args:tt)*) $expr:path) => {
-        _does_impl!(@base($($args)*) $expr)
+    (@unary($($args:tt)*)) => {
+        compile_error!("expected a trait expression, found nothing")
+    };
+    (@unary($($args:tt)*) $($expr:tt)+) => {
+        compile_error!(concat!(
+            "expected a trait, `!`, or parentheses, found `",
+            stringify!($($expr)+),
+            "`"
+        ))
     };
 
+    (@base(@value $value:ident, $($args:tt)*) $($trait:tt)*) => {{
+        struct Wrapper<T: ?Sized>(PhantomData<T>);
+
+        #[allow(dead_code)]
+        impl<T: ?Sized + $($trait)*> Wrapper<T> {
+            fn does_impl(&self) -> &'static True {
+                &True
+            }
+        }
+
+        fn wrap<T: ?Sized>(_: &T) -> Wrapper<T> {
+            Wrapper(PhantomData)
+        }
+
+        // Like below, except that the type is inferred from the value and
+        // method resolution prefers the inherent method over the trait's.
+        wrap(&$value).does_impl()
+    }};
     (@base($ty:ty, $($args:tt)*) $($trait:tt)*) => {{
         // Base case: computes whether `ty` implements `trait`.
         struct Wrapper<T: ?Sized>(PhantomData<T>);
@@ -0,0 +1,496 @@
+//! Formatting of failure messages during constant evaluation.
+//!
+//! Only `{}` placeholders are supported, with `{{` and `}}` as escapes.
+
+use core::{mem, slice, str};
+
+/// The maximum length of a formatted message. Longer messages are truncated.
+const CAPACITY: usize = 512;
+
+/// A value to be formatted into a message.
+#[derive(Clone, Copy)]
+pub enum Arg<'a> {
+    Str(&'a str),
+    Int(i128),
+    Uint(u128),
+    Bool(bool),
+    Char(char),
+    /// A string in double quotes, to show it as a value.
+    QuotedStr(&'a str),
+    /// A character in single quotes, to show it as a value.
+    QuotedChar(char),
+    /// Bytes as a list of integers, like `[1, 2, 3]`.
+    Bytes(&'a [u8]),
+    /// The bits of an `f32`, shown like `f32::from_bits(0x3fc00000)` to
+    /// identify the value exactly.
+    F32(u32),
+    /// The bits of an `f64`, shown like `f64::from_bits(0x3ff8000000000000)`.
+    F64(u64),
+}
+
+/// Converts a value into an `Arg` via inherent methods, since trait methods
+/// cannot be called in constant evaluation.
+///
+/// `arg` is for formatting into messages as-is, while `debug` is for showing
+/// the value in a failed comparison. `eq` compares values of the same type,
+/// including those that cannot be compared via `==` in constant evaluation.
+pub struct Wrap<T>(pub T);
+
+macro_rules! impl_wrap {
+    ($($variant:ident($conv:ty): $($t:ty),+;)+) => {
+        $($(impl Wrap<$t> {
+            pub const fn arg(self) -> Arg<'static> {
+                Arg::$variant(self.0 as $conv)
+            }
+
+            pub const fn debug(self) -> Arg<'static> {
+                self.arg()
+            }
+
+            pub const fn eq(self, other: $t) -> bool {
+                self.0 == other
+            }
+        })+)+
+    };
+}
+
+impl_wrap! {
+    Int(i128): i8, i16, i32, i64, i128, isize;
+    Uint(u128): u8, u16, u32, u64, u128, usize;
+    Bool(bool): bool;
+}
+
+macro_rules! impl_wrap_float {
+    ($($t:ident($bits:ty): $variant:ident;)+) => {
+        $(impl Wrap<$t> {
+            pub const fn arg(self) -> Arg<'static> {
+                Arg::$variant(cast::<$t, $bits>(self.0))
+            }
+
+            pub const fn debug(self) -> Arg<'static> {
+                self.arg()
+            }
+
+            /// Compares like `==`, but via the bits, as comparing floats in
+            /// constant functions requires Rust 1.82.
+            pub const fn eq(self, other: $t) -> bool {
+                let a = cast::<$t, $bits>(self.0);
+                let b = cast::<$t, $bits>(other);
+                let abs = !0 >> 1;
+                let infinity = cast::<$t, $bits>(<$t>::INFINITY);
+
+                // NaN is unequal to everything, while zeros of either sign
+                // are equal.
+                if a & abs > infinity || b & abs > infinity {
+                    false
+                } else {
+                    a == b || (a | b) & abs == 0
+                }
+            }
+        })+
+    };
+}
+
+impl_wrap_float! {
+    f32(u32): F32;
+    f64(u64): F64;
+}
+
+impl Wrap<char> {
+    pub const fn arg(self) -> Arg<'static> {
+        Arg::Char(self.0)
+    }
+
+    pub const fn debug(self) -> Arg<'static> {
+        Arg::QuotedChar(self.0)
+    }
+
+    pub const fn eq(self, other: char) -> bool {
+        self.0 == other
+    }
+}
+
+impl<'a> Wrap<&'a str> {
+    pub const fn arg(self) -> Arg<'a> {
+        Arg::Str(self.0)
+    }
+
+    pub const fn debug(self) -> Arg<'a> {
+        Arg::QuotedStr(self.0)
+    }
+
+    pub const fn eq(self, other: &str) -> bool {
+        bytes_eq(self.0.as_bytes(), other.as_bytes())
+    }
+}
+
+impl<'a> Wrap<&'a [u8]> {
+    pub const fn arg(self) -> Arg<'a> {
+        Arg::Bytes(self.0)
+    }
+
+    pub const fn debug(self) -> Arg<'a> {
+        self.arg()
+    }
+
+    pub const fn eq(self, other: &[u8]) -> bool {
+        bytes_eq(self.0, other)
+    }
+}
+
+/// The kind of value that a type is converted from by `arg`.
+#[derive(Clone, Copy)]
+pub enum Kind {
+    Int,
+    Uint,
+    Bool,
+    Char,
+    Str,
+    Bytes,
+    F32,
+    F64,
+    /// An `Arg` that was already converted, such as via `Wrap::debug`.
+    Arg,
+}
+
+mod sealed {
+    /// Keeps `ToArg` from being implemented outside of this crate, since `arg`
+    /// relies on `KIND` matching the type.
+    pub trait Sealed {}
+}
+
+/// A type that can be formatted into messages via `arg`.
+pub trait ToArg: sealed::Sealed + Copy {
+    const KIND: Kind;
+}
+
+macro_rules! impl_to_arg {
+    ($($kind:ident: $($t:ty),+;)+) => {
+        $($(
+            impl sealed::Sealed for $t {}
+
+            impl ToArg for $t {
+                const KIND: Kind = Kind::$kind;
+            }
+        )+)+
+    };
+}
+
+impl_to_arg! {
+    Int: i8, i16, i32, i64, i128, isize;
+    Uint: u8, u16, u32, u64, u128, usize;
+    Bool: bool;
+    Char: char;
+    F32: f32;
+    F64: f64;
+}
+
+impl sealed::Sealed for &str {}
+
+impl ToArg for &str {
+    const KIND: Kind = Kind::Str;
+}
+
+impl sealed::Sealed for &[u8] {}
+
+impl ToArg for &[u8] {
+    const KIND: Kind = Kind::Bytes;
+}
+
+impl<'a> sealed::Sealed for Arg<'a> {}
+
+impl<'a> ToArg for Arg<'a> {
+    const KIND: Kind = Kind::Arg;
+}
+
+/// Converts a message argument into an `Arg`.
+///
+/// Unlike with `Wrap`, the type is dispatched on via a trait bound rather
+/// than inherent methods, so integer literals without a suffix fall back to
+/// `i32` instead of being ambiguous. The value is then reinterpreted as the
+/// type of its kind, since trait methods cannot be called in constant
+/// evaluation.
+pub const fn arg<'a, T: ToArg + 'a>(value: T) -> Arg<'a> {
+    let size = mem::size_of::<T>();
+    match T::KIND {
+        Kind::Int if size == 1 => Arg::Int(cast::<T, i8>(value) as i128),
+        Kind::Int if size == 2 => Arg::Int(cast::<T, i16>(value) as i128),
+        Kind::Int if size == 4 => Arg::Int(cast::<T, i32>(value) as i128),
+        Kind::Int if size == 8 => Arg::Int(cast::<T, i64>(value) as i128),
+        Kind::Int => Arg::Int(cast::<T, i128>(value)),
+        Kind::Uint if size == 1 => Arg::Uint(cast::<T, u8>(value) as u128),
+        Kind::Uint if size == 2 => Arg::Uint(cast::<T, u16>(value) as u128),
+        Kind::Uint if size == 4 => Arg::Uint(cast::<T, u32>(value) as u128),
+        Kind::Uint if size == 8 => Arg::Uint(cast::<T, u64>(value) as u128),
+        Kind::Uint => Arg::Uint(cast::<T, u128>(value)),
+        Kind::Bool => Arg::Bool(cast::<T, bool>(value)),
+        Kind::Char => Arg::Char(cast::<T, char>(value)),
+        Kind::Str => Arg::Str(cast::<T, &'a str>(value)),
+        Kind::Bytes => Arg::Bytes(cast::<T, &'a [u8]>(value)),
+        Kind::F32 => Arg::F32(cast::<T, u32>(value)),
+        Kind::F64 => Arg::F64(cast::<T, u64>(value)),
+        Kind::Arg => cast::<T, Arg<'a>>(value),
+    }
+}
+
+/// Converts `value` into an `Arg` as the type of `like`, so that literals
+/// take on the type of the value they are compared against.
+pub const fn arg_like<'a, T: ToArg + 'a>(_: T, value: T) -> Arg<'a> {
+    arg(value)
+}
+
+union Cast<T: Copy, U: Copy> {
+    from: T,
+    to: U,
+}
+
+/// Reinterprets `value` as `U`, which must be the same type up to lifetimes,
+/// an integer of the same size and signedness, or the bits of a float.
+const fn cast<T: Copy, U: Copy>(value: T) -> U {
+    // SAFETY: `arg` only casts between types of the same layout, and `T`
+    // outlives the lifetimes in `U`.
+    unsafe { Cast { from: value }.to }
+}
+
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Panics with the formatted message if `cond` is `false`.
+///
+/// The message is validated regardless of `cond` so that mistakes in it are
+/// not hidden until the assertion fails.
+#[track_caller]
+pub const fn assert(cond: bool, fmt: &str, args: &[Arg]) {
+    validate(fmt.as_bytes(), args.len());
+    if !cond {
+        fail(fmt, args);
+    }
+}
+
+/// Panics with the formatted message.
+#[track_caller]
+pub const fn fail(fmt: &str, args: &[Arg]) -> ! {
+    validate(fmt.as_bytes(), args.len());
+    let buf = format(fmt.as_bytes(), args);
+
+    // SAFETY: `format` never splits a UTF-8 sequence.
+    let msg = unsafe {
+        str::from_utf8_unchecked(slice::from_raw_parts(
+            buf.bytes.as_ptr(),
+            buf.len,
+        ))
+    };
+    panic!("{}", msg)
+}
+
+#[track_caller]
+const fn validate(fmt: &[u8], args: usize) {
+    let mut placeholders = 0;
+    let mut i = 0;
+    while i < fmt.len() {
+        match (fmt[i], next(fmt, i)) {
+            (b'{', b'}') => placeholders += 1,
+            (b'{', b'{') | (b'}', b'}') => {}
+            (b'{', _) | (b'}', _) => {
+                panic!("invalid format string: unsupported or unmatched brace")
+            }
+            _ => {
+                i += 1;
+                continue;
+            }
+        }
+        i += 2;
+    }
+
+    if placeholders != args {
+        panic!("format string placeholders do not match the argument count");
+    }
+}
+
+/// Returns the byte after `i`, or 0 if there is none.
+const fn next(fmt: &[u8], i: usize) -> u8 {
+    if i + 1 < fmt.len() {
+        fmt[i + 1]
+    } else {
+        0
+    }
+}
+
+struct Buf {
+    bytes: [u8; CAPACITY],
+    len: usize,
+}
+
+const fn format(fmt: &[u8], args: &[Arg]) -> Buf {
+    let mut buf = Buf {
+        bytes: [0; CAPACITY],
+        len: 0,
+    };
+    let mut arg = 0;
+    let mut i = 0;
+    while i < fmt.len() {
+        match (fmt[i], next(fmt, i)) {
+            (b'{', b'}') => {
+                buf = push_arg(buf, args[arg]);
+                arg += 1;
+                i += 2;
+            }
+            (b'{', b'{') | (b'}', b'}') => {
+                buf = push_byte(buf, fmt[i]);
+                i += 2;
+            }
+            (byte, _) => {
+                buf = push_byte(buf, byte);
+                i += 1;
+            }
+        }
+    }
+    buf
+}
+
+const fn push_arg(buf: Buf, arg: Arg) -> Buf {
+    match arg {
+        Arg::Str(s) => push_str(buf, s.as_bytes()),
+        Arg::Int(n) if n < 0 => {
+            push_uint(push_byte(buf, b'-'), n.unsigned_abs())
+        }
+        Arg::Int(n) => push_uint(buf, n as u128),
+        Arg::Uint(n) => push_uint(buf, n),
+        Arg::Bool(true) => push_str(buf, b"true"),
+        Arg::Bool(false) => push_str(buf, b"false"),
+        Arg::Char(c) => push_char(buf, c),
+        Arg::QuotedStr(s) => {
+            push_byte(push_str(push_byte(buf, b'"'), s.as_bytes()), b'"')
+        }
+        Arg::QuotedChar(c) => {
+            push_byte(push_char(push_byte(buf, b'\''), c), b'\'')
+        }
+        Arg::Bytes(bytes) => push_bytes(buf, bytes),
+        Arg::F32(bits) => push_float(buf, b"f32", bits as u64, 8),
+        Arg::F64(bits) => push_float(buf, b"f64", bits, 16),
+    }
+}
+
+/// Pushes the bits of a float as `<ty>::from_bits(0x...)`, padded to
+/// `digits` hexadecimal digits.
+const fn push_float(mut buf: Buf, ty: &[u8], bits: u64, digits: u32) -> Buf {
+    buf = push_str(push_str(buf, ty), b"::from_bits(0x");
+    let mut shift = digits * 4;
+    while shift > 0 {
+        shift -= 4;
+        buf =
+            push_byte(buf, b"0123456789abcdef"[(bits >> shift) as usize & 0xF]);
+    }
+    push_byte(buf, b')')
+}
+
+const fn push_bytes(mut buf: Buf, bytes: &[u8]) -> Buf {
+    buf = push_byte(buf, b'[');
+    let mut i = 0;
+    while i < bytes.len() {
+        if i > 0 {
+            buf = push_str(buf, b", ");
+        }
+        buf = push_uint(buf, bytes[i] as u128);
+        i += 1;
+    }
+    push_byte(buf, b']')
+}
+
+const fn push_byte(mut buf: Buf, byte: u8) -> Buf {
+    if buf.len < CAPACITY {
+        buf.bytes[buf.len] = byte;
+        buf.len += 1;
+    }
+    buf
+}
+
+const fn push_str(mut buf: Buf, s: &[u8]) -> Buf {
+    let start = buf.len;
+    let mut i = 0;
+    while i < s.len() && buf.len < CAPACITY {
+        buf.bytes[buf.len] = s[i];
+        buf.len += 1;
+        i += 1;
+    }
+
+    // Never end on a partial UTF-8 sequence when truncating.
+    if i < s.len() {
+        while buf.len > start && s[i] & 0xC0 == 0x80 {
+            buf.len -= 1;
+            i -= 1;
+        }
+    }
+    buf
+}
+
+const fn push_uint(mut buf: Buf, mut n: u128) -> Buf {
+    let mut digits = [0u8; 39];
+    let mut start = digits.len();
+    loop {
+        start -= 1;
+        digits[start] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+
+    while start < digits.len() {
+        buf = push_byte(buf, digits[start]);
+        start += 1;
+    }
+    buf
+}
+
+const fn push_char(mut buf: Buf, c: char) -> Buf {
+    let c = c as u32;
+    let (bytes, len) = if c < 0x80 {
+        ([c as u8, 0, 0, 0], 1)
+    } else if c < 0x800 {
+        ([0xC0 | (c >> 6) as u8, 0x80 | (c & 0x3F) as u8, 0, 0], 2)
+    } else if c < 0x10000 {
+        (
+            [
+                0xE0 | (c >> 12) as u8,
+                0x80 | ((c >> 6) & 0x3F) as u8,
+                0x80 | (c & 0x3F) as u8,
+                0,
+            ],
+            3,
+        )
+    } else {
+        (
+            [
+                0xF0 | (c >> 18) as u8,
+                0x80 | ((c >> 12) & 0x3F) as u8,
+                0x80 | ((c >> 6) & 0x3F) as u8,
+                0x80 | (c & 0x3F) as u8,
+            ],
+            4,
+        )
+    };
+
+    // Characters are never split when truncating.
+    if buf.len + len > CAPACITY {
+        return buf;
+    }
+
+    let mut i = 0;
+    while i < len {
+        buf = push_byte(buf, bytes[i]);
+        i += 1;
+    }
+    buf
+}
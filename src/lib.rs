@@ -112,14 +112,25 @@ pub use proc_static_assertions::assert;
 pub extern crate core as _core;
 
 mod assert_align;
+mod assert_assoc;
+mod assert_auto;
 mod assert_cfg;
+mod assert_const_fn;
+mod assert_discriminant;
 mod assert_fields;
+mod assert_fn;
+mod assert_future;
 mod assert_impl;
 mod assert_obj_safe;
+mod assert_offset;
+mod assert_padding;
 mod assert_size;
 mod assert_trait;
+mod assert_transparent;
 mod assert_type;
+mod assert_variants;
 mod const_assert;
+mod const_assert_str;
 mod does_impl;
 
 // Utility macros.
@@ -128,6 +139,33 @@ mod does_impl;
 // crate's semver requirements.
 mod util;
 
+// Formatting of custom failure messages.
+//
+// This module should never be used publicly and is not part of this crate's
+// semver requirements.
+#[cfg(const_msg)]
+#[doc(hidden)]
+#[path = "fmt.rs"]
+pub mod _fmt;
+
+// String operations in constant evaluation.
+//
+// This module should never be used publicly and is not part of this crate's
+// semver requirements.
+#[cfg(const_msg)]
+#[doc(hidden)]
+#[path = "str.rs"]
+pub mod _str;
+
+// Slice operations in constant evaluation.
+//
+// This module should never be used publicly and is not part of this crate's
+// semver requirements.
+#[cfg(const_msg)]
+#[doc(hidden)]
+#[path = "slice.rs"]
+pub mod _slice;
+
 // Type-level booleans.
 //
 // This module should never be used publicly and is not part of this crate's
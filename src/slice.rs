@@ -0,0 +1,73 @@
+//! Slice operations for use in constant evaluation, where elements cannot be
+//! compared through traits.
+
+/// A slice of primitive elements with operations via inherent methods.
+pub struct Slice<'a, T>(pub &'a [T]);
+
+macro_rules! impl_slice {
+    ($($t:ty),+) => {
+        $(impl<'a> Slice<'a, $t> {
+            /// Returns the length of the longest prefix that is sorted in
+            /// ascending order, which is the index of the first element that
+            /// is less than its predecessor if there is one.
+            pub const fn sorted_len(self) -> usize {
+                let s = self.0;
+                if s.is_empty() {
+                    return 0;
+                }
+                let mut i = 1;
+                while i < s.len() && s[i - 1] <= s[i] {
+                    i += 1;
+                }
+                i
+            }
+
+            /// Returns the length of the longest prefix without duplicates,
+            /// which is the index of the first element that repeats an earlier
+            /// one if there is one.
+            pub const fn unique_len(self) -> usize {
+                let s = self.0;
+                if s.is_empty() {
+                    return 0;
+                }
+                let mut i = 1;
+
+                // Duplicates are adjacent in sorted slices, which avoids
+                // comparing all pairs for large lookup tables.
+                if Slice(s).sorted_len() == s.len() {
+                    while i < s.len() && s[i - 1] != s[i] {
+                        i += 1;
+                    }
+                    return i;
+                }
+
+                while i < s.len() {
+                    let mut j = 0;
+                    while j < i {
+                        if s[j] == s[i] {
+                            return i;
+                        }
+                        j += 1;
+                    }
+                    i += 1;
+                }
+                s.len()
+            }
+
+            /// Returns the index of the first element equal to `x`, or the
+            /// length if there is none.
+            pub const fn position(self, x: $t) -> usize {
+                let s = self.0;
+                let mut i = 0;
+                while i < s.len() && s[i] != x {
+                    i += 1;
+                }
+                i
+            }
+        })+
+    };
+}
+
+impl_slice!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, bool, char
+);
@@ -0,0 +1,29 @@
+//! String operations for use in constant evaluation, where most `str` methods
+//! are not available.
+
+/// Returns whether `s` starts with `prefix`.
+pub const fn starts_with(s: &str, prefix: &str) -> bool {
+    let (s, prefix) = (s.as_bytes(), prefix.as_bytes());
+    if s.len() < prefix.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < prefix.len() {
+        if s[i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns the length of the longest prefix of `s` that is ASCII, which is
+/// the index of the first non-ASCII byte if there is one.
+pub const fn ascii_len(s: &str) -> usize {
+    let s = s.as_bytes();
+    let mut i = 0;
+    while i < s.len() && s[i] < 0x80 {
+        i += 1;
+    }
+    i
+}
@@ -1,3 +1,5 @@
+/// Returns the first expression. No longer used, but kept since it was
+/// exported by earlier versions.
 #[doc(hidden)]
 #[macro_export]
 macro_rules! _head {
@@ -5,3 +7,211 @@ macro_rules! _head {
         $head
     };
 }
+
+/// Asserts that a constant expression is `true`, with an optional custom
+/// failure message.
+#[cfg(const_msg)]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _const_assert {
+    ($x:expr) => {
+        const _: () = $crate::_fmt::assert(
+            $x,
+            "assertion failed: {}",
+            &[$crate::_fmt::Arg::Str(stringify!($x))],
+        );
+    };
+    ($x:expr, $msg:expr $(, $arg:expr)*) => {
+        const _: () = $crate::_fmt::assert(
+            $x,
+            $msg,
+            &[$($crate::_fmt::arg($arg)),*],
+        );
+    };
+}
+
+/// Asserts that a constant expression is `true`. Custom failure messages are
+/// not supported by the compiler and are ignored.
+#[cfg(not(const_msg))]
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! _const_assert {
+    ($x:expr $(, $msg:expr)*) => {
+        const _: $crate::True = _to_bool!($x);
+    };
+}
+
+/// Appends the next token to the tokens collected so far, while tracking the
+/// nesting depth of `<` and `>` as a stack of `<` tokens, and passes the new
+/// state back to `$mac` after `$prefix`.
+///
+/// Callers match the tokens that end what they collect at the depth they are
+/// interested in first, and hand all others to this macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _track_generics {
+    ($mac:ident [$($prefix:tt)*] [$($cur:tt)*] [$($d:tt)*] < $($rest:tt)*) => {
+        $crate::$mac! { $($prefix)* [$($cur)* <] [< $($d)*] $($rest)* }
+    };
+    ($mac:ident [$($prefix:tt)*] [$($cur:tt)*] [< $($d:tt)*] > $($rest:tt)*) => {
+        $crate::$mac! { $($prefix)* [$($cur)* >] [$($d)*] $($rest)* }
+    };
+    ($mac:ident [$($prefix:tt)*] [$($cur:tt)*] [< < $($d:tt)*] >> $($rest:tt)*) => {
+        $crate::$mac! { $($prefix)* [$($cur)* >>] [$($d)*] $($rest)* }
+    };
+    ($mac:ident [$($prefix:tt)*] [$($cur:tt)*] $d:tt $t:tt $($rest:tt)*) => {
+        $crate::$mac! { $($prefix)* [$($cur)* $t] $d $($rest)* }
+    };
+}
+
+/// Splits `for<...> <body> where <bounds>` into `for(...) where(...) <body>`
+/// and passes it on to `$mac`.
+///
+/// The generics are delimited via [`_track_generics!`]. Higher-ranked function
+/// pointer types like `for<'a> fn(&'a u8)` are passed on as the body as-is.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _for_generics {
+    (@where $mac:ident [$($g:tt)*] [$($body:tt)*] where $($bound:tt)*) => {
+        $crate::$mac!(for($($g)*) where($($bound)*) $($body)*);
+    };
+    (@where $mac:ident [$($g:tt)*] [$($body:tt)*]) => {
+        $crate::$mac!(for($($g)*) where() $($body)*);
+    };
+    (@where $mac:ident [$($g:tt)*] [$($body:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::_for_generics!(@where $mac [$($g)*] [$($body)* $t] $($rest)*);
+    };
+
+    (@end $mac:ident [$($g:tt)*] fn $($rest:tt)*) => {
+        $crate::_for_generics!(@where $mac [] [for<$($g)*> fn] $($rest)*);
+    };
+    (@end $mac:ident [$($g:tt)*] unsafe $($rest:tt)*) => {
+        $crate::_for_generics!(@where $mac [] [for<$($g)*> unsafe] $($rest)*);
+    };
+    (@end $mac:ident [$($g:tt)*] extern $($rest:tt)*) => {
+        $crate::_for_generics!(@where $mac [] [for<$($g)*> extern] $($rest)*);
+    };
+    (@end $mac:ident [$($g:tt)*] $($rest:tt)*) => {
+        $crate::_for_generics!(@where $mac [$($g)*] [] $($rest)*);
+    };
+
+    ($mac:ident [$($g:tt)*] [] > $($rest:tt)*) => {
+        $crate::_for_generics!(@end $mac [$($g)*] $($rest)*);
+    };
+    ($mac:ident [$($g:tt)*] [<] >> $($rest:tt)*) => {
+        $crate::_for_generics!(@end $mac [$($g)* >] $($rest)*);
+    };
+    ($mac:ident $g:tt $depth:tt $($rest:tt)+) => {
+        $crate::_track_generics!(_for_generics [$mac] $g $depth $($rest)+);
+    };
+}
+
+/// Turns `path(args)` into a call where each `_` argument is replaced with a
+/// call to a `placeholder` function, which the caller must define as
+/// `fn placeholder<T>() -> T`.
+///
+/// The path is munched until the final parenthesized group, since a `path`
+/// fragment cannot be followed by one.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _call_placeholders {
+    (@args [$($f:tt)+] [$($done:tt)*]) => {
+        $($f)+($($done)*)
+    };
+    (@args $f:tt [$($done:tt)*] _ $(, $($rest:tt)*)?) => {
+        $crate::_call_placeholders!(
+            @args $f [$($done)* placeholder(),] $($($rest)*)?
+        )
+    };
+    (@args $f:tt [$($done:tt)*] $arg:expr $(, $($rest:tt)*)?) => {
+        $crate::_call_placeholders!(@args $f [$($done)* $arg,] $($($rest)*)?)
+    };
+
+    ([$($f:tt)+] ($($args:tt)*)) => {
+        $crate::_call_placeholders!(@args [$($f)+] [] $($args)*)
+    };
+    ([$($f:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::_call_placeholders!([$($f)* $next] $($rest)*)
+    };
+    ([$($f:tt)*]) => {
+        compile_error!(concat!(
+            "expected a function call like `f(_, _)`, found `",
+            stringify!($($f)*),
+            "`"
+        ))
+    };
+}
+
+/// Asserts a condition in an associated constant of a local type that copies
+/// the generics of the caller, so that it is evaluated once the caller is
+/// instantiated.
+///
+/// The names of the parameters are collected from the generics, since the
+/// type must be instantiated with them. Type and lifetime parameters are also
+/// collected into the type of a `PhantomData` field so that they are used.
+#[cfg(const_msg)]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _const_assert_generic {
+    (@param $g:tt $b:tt [$($a:tt)*] [$($p:tt)*] $x:tt) => {
+        $crate::_const_assert_generic!(@emit $g $b [$($a)*] [$($p)*] $x);
+    };
+    (@param $g:tt $b:tt [$($a:tt)*] $p:tt $x:tt const $n:ident $($rest:tt)*) => {
+        $crate::_const_assert_generic!(
+            @skip $g $b [$($a)* $n,] $p $x [] [] $($rest)*
+        );
+    };
+    (@param $g:tt $b:tt [$($a:tt)*] [$($p:tt)*] $x:tt $l:lifetime $($rest:tt)*) => {
+        $crate::_const_assert_generic!(
+            @skip $g $b [$($a)* $l,] [$($p)* &$l (),] $x [] [] $($rest)*
+        );
+    };
+    (@param $g:tt $b:tt [$($a:tt)*] [$($p:tt)*] $x:tt $n:ident $($rest:tt)*) => {
+        $crate::_const_assert_generic!(
+            @skip $g $b [$($a)* $n,] [$($p)* *const $n,] $x [] [] $($rest)*
+        );
+    };
+
+    // Skips bounds up to the next top-level comma.
+    (@skip $g:tt $b:tt $a:tt $p:tt $x:tt $bound:tt [] , $($rest:tt)*) => {
+        $crate::_const_assert_generic!(@param $g $b $a $p $x $($rest)*);
+    };
+    (@skip $g:tt $b:tt $a:tt $p:tt $x:tt $bound:tt $d:tt $($rest:tt)+) => {
+        $crate::_track_generics!(
+            _const_assert_generic [@skip $g $b $a $p $x] $bound $d $($rest)+
+        );
+    };
+    (@skip $g:tt $b:tt $a:tt $p:tt $x:tt $bound:tt $d:tt) => {
+        $crate::_const_assert_generic!(@param $g $b $a $p $x);
+    };
+
+    (@emit $g:tt $b:tt $a:tt $p:tt [$x:expr $(,)?]) => {
+        $crate::_const_assert_generic!(
+            @emit $g $b $a $p
+            [$x, "assertion failed: {}", stringify!($x)]
+        );
+    };
+    (
+        @emit [$($g:tt)*] [$($b:tt)*] [$($a:tt)*] [$($p:tt)*]
+        [$x:expr, $msg:expr $(, $arg:expr)* $(,)?]
+    ) => {{
+        #[allow(dead_code)]
+        struct Check<$($g)*>($crate::_core::marker::PhantomData<($($p)*)>)
+        where
+            $($b)*;
+
+        impl<$($g)*> Check<$($a)*>
+        where
+            $($b)*
+        {
+            const ASSERT: () = $crate::_fmt::assert(
+                $x,
+                $msg,
+                &[$($crate::_fmt::arg($arg)),*],
+            );
+        }
+
+        #[allow(clippy::let_unit_value)]
+        let _ = Check::<$($a)*>::ASSERT;
+    }};
+}
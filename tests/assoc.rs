@@ -0,0 +1,35 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+use core::ops::{Deref, Range};
+
+trait Codec {
+    type Input: ?Sized;
+    type Output;
+    const VERSION: u32;
+    const MAX_LEN: usize;
+    const MASK: u64;
+}
+
+#[allow(dead_code)]
+struct Utf8;
+
+impl Codec for Utf8 {
+    type Input = str;
+    type Output = [u8; 4];
+    const VERSION: u32 = 3;
+    const MAX_LEN: usize = 4;
+    const MASK: u64 = !0;
+}
+
+assert_impl_assoc!(Utf8: Codec<Input = str, Output = [u8; 4]>);
+assert_impl_assoc!(Range<u8>: Iterator<Item = u8>, core::iter::IntoIterator<IntoIter = Range<u8>,>,);
+assert_impl_assoc!(&'static str: Deref<Target = str>);
+
+assert_assoc_const_eq!(<Utf8 as Codec>::VERSION, 3);
+assert_assoc_const_eq!(<Utf8 as Codec>::VERSION, 1 + 2,);
+assert_assoc_const_eq!(<Utf8 as Codec>::MAX_LEN, 2 * 2);
+assert_assoc_const_eq!(<Utf8 as Codec>::MASK, 18446744073709551615);
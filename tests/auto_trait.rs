@@ -0,0 +1,30 @@
+#![no_std]
+#![deny(unsafe_code)]
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+use core::cell::Cell;
+use core::marker::{PhantomData, PhantomPinned};
+
+struct Plain {
+    a: u32,
+    b: &'static str,
+}
+
+struct Local {
+    a: u32,
+    _marker: PhantomData<*const u8>,
+}
+
+struct Pinned(PhantomPinned);
+
+assert_send!(Plain, u8, &'static [u16]);
+assert_sync!(Plain, Pinned,);
+assert_thread_safe!(Plain, Pinned);
+assert_unpin!(Plain, Local);
+
+assert_not_send!(Local, *mut u8);
+assert_not_sync!(Local, Cell<u8>,);
+assert_not_unpin!(Pinned, (u8, Pinned));
@@ -1,5 +1,6 @@
 #![no_std]
 #![deny(unsafe_code)]
+#![allow(clippy::bool_comparison, clippy::eq_op)]
 
 #[macro_use]
 extern crate static_assertions;
@@ -13,3 +14,13 @@ const FIVE: usize = 5;
 
 const_assert!(FIVE * 2 == 10);
 const_assert!(FIVE > 2);
+
+const_assert!(FIVE == 5, "FIVE is {}", FIVE);
+const_assert!(FIVE != 0, "FIVE must be non-zero");
+const_assert_eq!(FIVE, 5, 10 / 2; "FIVE is {}", FIVE);
+const_assert_ne!(FIVE, 4; "FIVE is {} ({})", FIVE, 'x');
+const_assert_lt!(1, FIVE, 6; "FIVE {} out of range", FIVE);
+const_assert_le!(5, FIVE, 5; "{}", true);
+const_assert_gt!(6, FIVE, 4, -1; "{{FIVE}} = {}", FIVE);
+const_assert_ge!(FIVE, 5; "FIVE is {}", "five");
+const_assert!(FIVE == 5, "expected {}, {} or {}", 5, -5, 5u8);
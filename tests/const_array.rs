@@ -0,0 +1,59 @@
+#![cfg(const_msg)]
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+const PRIMES: [u16; 6] = [2, 3, 5, 7, 11, 13];
+const SIGNED: &[i64] = &[i64::MIN, -1, 0, 0, i64::MAX];
+const LETTERS: [char; 3] = ['a', 'b', 'é'];
+const FLAGS: [bool; 2] = [false, true];
+const EMPTY: [u8; 0] = [];
+const NAMES: &[&str] = &["red", "green", "blue"];
+
+const_assert_sorted!(PRIMES);
+const_assert_sorted!(SIGNED);
+const_assert_sorted!(LETTERS);
+const_assert_sorted!(FLAGS);
+const_assert_sorted!(EMPTY);
+const_assert_sorted!([42usize]);
+const_assert_sorted!(PRIMES; "`PRIMES` must stay sorted");
+
+const_assert_unique!(PRIMES);
+const_assert_unique!(LETTERS);
+const_assert_unique!(FLAGS);
+const_assert_unique!(EMPTY);
+const_assert_unique!([u128::MAX, 0]);
+const_assert_unique!(LETTERS; "duplicate letter");
+const_assert_unique!(EMPTY; "empty table");
+
+const_assert_len!(PRIMES, 6);
+const_assert_len!(SIGNED, 5);
+const_assert_len!(EMPTY, 0);
+const_assert_len!(NAMES, 3);
+const_assert_len!([0u8; 256], 256; "expected {} bytes", 256);
+
+const_assert_contains!(PRIMES, 2);
+const_assert_contains!(PRIMES, PRIMES[5]);
+const_assert_contains!(SIGNED, i64::MIN);
+const_assert_contains!(LETTERS, 'é');
+const_assert_contains!(FLAGS, true);
+const_assert_contains!([-1i8, 1], -1; "missing {}", -1);
+
+const fn squares() -> [u32; 4096] {
+    let mut table = [0; 4096];
+    let mut i = 0;
+    while i < table.len() {
+        table[i] = (i * i) as u32;
+        i += 1;
+    }
+    table
+}
+
+// Sorted tables are checked for duplicates in linear time.
+const SQUARES: [u32; 4096] = squares();
+
+const_assert_sorted!(SQUARES);
+const_assert_unique!(SQUARES);
+const_assert_contains!(SQUARES, 4095 * 4095);
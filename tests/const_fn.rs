@@ -0,0 +1,39 @@
+#![no_std]
+#![deny(unsafe_code)]
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+use core::cell::Cell;
+use core::marker::PhantomData;
+
+struct Guard(u8);
+
+impl Guard {
+    const fn new(value: u8) -> Self {
+        Guard(value)
+    }
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {}
+}
+
+struct Typed<T>(PhantomData<T>);
+
+impl<T> Typed<T> {
+    const fn new() -> Self {
+        Typed(PhantomData)
+    }
+}
+
+const fn add(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+const BASE: u32 = 40;
+
+assert_const_fn!(add(BASE, 2));
+assert_const_fn!(Guard::new(1), Typed::<Guard>::new(),);
+assert_const_fn!(Cell::new(0u8), core::mem::size_of::<Guard>());
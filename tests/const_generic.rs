@@ -0,0 +1,64 @@
+#![cfg(const_msg)]
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+use core::mem::{align_of, size_of};
+
+trait Packet {
+    const LEN: usize;
+}
+
+struct Ping;
+
+impl Packet for Ping {
+    const LEN: usize = 8;
+}
+
+fn fits<T>() {
+    const_assert_generic!(T: size_of::<T>() <= 16);
+}
+
+fn same_size<T, U>() -> usize {
+    const_assert_generic!(T, U: size_of::<T>() == size_of::<U>(),);
+    size_of::<T>()
+}
+
+fn copy_to<'a, P: Packet + ?Sized, const N: usize>(_: &'a mut [u8; N]) {
+    const_assert_generic!(
+        for<'a, P: Packet + ?Sized, const N: usize> P::LEN <= N && N > 0,
+        "packet of {} bytes does not fit in {} bytes",
+        P::LEN,
+        N,
+    );
+}
+
+fn nested<T: Into<Option<(u8, u8)>>, U>()
+where
+    U: AsRef<[T]>,
+{
+    const_assert_generic!(
+        for<T: Into<Option<(u8, u8)>>, U> align_of::<U>() >= align_of::<T>()
+        where U: AsRef<[T]>
+    );
+}
+
+struct Buffer<T>(T);
+
+impl<T> Buffer<T> {
+    fn new(value: T) -> Self {
+        const_assert_generic!(T: size_of::<T>() > 0, "zero-sized types are not supported");
+        Buffer(value)
+    }
+}
+
+#[test]
+fn instantiate() {
+    fits::<[u64; 2]>();
+    assert_eq!(same_size::<u32, [u8; 4]>(), 4);
+    copy_to::<Ping, 8>(&mut [0; 8]);
+    nested::<(u8, u8), [(u8, u8); 2]>();
+    Buffer::new(1u8);
+}
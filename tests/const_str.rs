@@ -0,0 +1,26 @@
+#![cfg(const_msg)]
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+const VERSION: &str = "2.1.0-beta";
+const GREETING: &str = "grüß dich";
+
+const_assert_str_eq!(GREETING, "grüß dich", concat!("grüß", " ", "dich"));
+const_assert_str_eq!("", ""; "empty strings differ");
+
+const_assert_str_starts_with!(VERSION, "2.");
+const_assert_str_starts_with!(GREETING, "grü");
+const_assert_str_starts_with!(GREETING, GREETING);
+const_assert_str_starts_with!("", "",);
+const_assert_str_starts_with!(concat!("grüß", " dich"), "grüß "; "prefix changed");
+
+const_assert_str_len!(GREETING, 11);
+const_assert_str_len!("", 0,);
+const_assert_str_len!(VERSION, VERSION.len(); "length of {} changed", VERSION);
+
+const_assert_str_ascii!(VERSION);
+const_assert_str_ascii!("\x7f");
+const_assert_str_ascii!(""; "empty string is not ASCII");
@@ -0,0 +1,29 @@
+#![cfg(const_msg)]
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+const MAGIC: &[u8] = b"\x89PNG";
+const NAME: &str = "static_assertions";
+const SMALL: i8 = -128;
+const LARGE: u128 = u128::MAX;
+
+const_assert_eq_typed!(&[u8]: MAGIC, b"\x89PNG", &[0x89, 0x50, 0x4e, 0x47]);
+const_assert_eq_typed!(&[u8]: b"", &[]);
+const_assert_eq_typed!(&str: NAME, concat!("static", "_", "assertions"));
+const_assert_eq_typed!(&str: "", "");
+const_assert_eq_typed!(i8: SMALL, -128, i8::MIN);
+const_assert_eq_typed!(u128: LARGE, !0);
+const_assert_eq_typed!(isize: -1, -1);
+const_assert_eq_typed!(bool: true, !false);
+const_assert_eq_typed!(char: 'é', '\u{e9}'; "unexpected {}", 'é');
+const_assert_eq_typed!(u8: 1 + 1, 2, 4 / 2; "arithmetic is broken");
+
+const RATIO: f64 = 1.5;
+
+const_assert_eq_typed!(f64: RATIO, 3.0 / 2.0, 1.5; "ratio is {}", RATIO);
+const_assert_eq_typed!(f32: 0.0, -0.0);
+const_assert_eq_typed!(f32: f32::INFINITY, 1.0 / 0.0);
+const_assert_eq_typed!(f64: f64::MIN_POSITIVE, 2.2250738585072014e-308);
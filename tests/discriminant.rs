@@ -0,0 +1,36 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+#[allow(dead_code)]
+#[repr(i8)]
+enum Sign {
+    Negative = -1,
+    Zero,
+    Positive,
+}
+
+assert_discriminant!(Sign::Negative == -1);
+assert_discriminant!(Sign::Zero == 0, Sign::Positive == 1,);
+assert_discriminants_contiguous!(Sign: Negative = -1, Zero, Positive);
+
+mod m {
+    #[allow(dead_code)]
+    #[repr(u8)]
+    pub enum Opcode {
+        Ping = 0x01,
+        Pong,
+    }
+}
+
+assert_discriminant!(m::Opcode::Ping == 0x01);
+assert_discriminants_contiguous!(m::Opcode: Ping = 1, Pong,);
+
+#[allow(dead_code)]
+enum Single {
+    Only,
+}
+
+assert_discriminants_contiguous!(Single: Only);
@@ -5,6 +5,8 @@
 extern crate static_assertions;
 
 assert_size_eq!(u8, u8, (u8,), [u8; 1]);
+assert_size_eq!(for<T> Option<&'static T>, &'static T where T: 'static);
+assert_size_eq!(for<'a, T: ?Sized + 'a> &'a T, &'a mut T, *const T);
 
 mod assoc_type {
     trait Trait {
@@ -20,10 +22,9 @@ mod assoc_type {
 
     assert_size_eq!(<Value as Trait>::AssocItem, Value);
 
-    // TODO: Is this possible?
-    // pub fn test<T: Trait>() {
-    //     assert_size_eq!(<T as Trait>::AssocItem, T);
-    // }
+    // Older compilers, including the minimum supported version, reject this
+    // as a transmute between dependently-sized types:
+    // assert_size_eq!(for<T> <T as Trait>::AssocItem, T);
 }
 
 // Placed in separate module so that DropCounter's fields are private
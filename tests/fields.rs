@@ -30,3 +30,19 @@ use m::Bar as Baz;
 
 assert_fields!(m::Bar<str>: inner, nul);
 assert_fields!(Baz<dyn Send>: inner, nul);
+
+assert_fields_ty!(Foo::A: x: u8, y: u8);
+assert_fields_ty!(Foo::B: 0: u8);
+assert_fields_ty!(m::Bar<str>: inner: str, nul: ());
+assert_fields_ty!(Baz<dyn Send>: inner: dyn Send,);
+
+#[allow(dead_code)]
+struct Pair<'a>(&'a str, [u8; 4]);
+
+assert_fields_ty!(Pair<'static>: 0: &'static str, 1: [u8; 4]);
+
+assert_fields_exact!(Foo::A: x, y);
+assert_fields_exact!(Foo::A: y, x,);
+assert_fields_exact!(Foo::B: 0);
+assert_fields_exact!(m::Bar<str>: inner, nul);
+assert_fields_exact!(Pair<'static>: 1, 0);
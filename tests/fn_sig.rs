@@ -0,0 +1,40 @@
+#![no_std]
+#![deny(unsafe_code)]
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+use core::mem;
+
+struct Handler;
+
+impl Handler {
+    fn handle(&self, _: &[u8]) -> Option<usize> {
+        None
+    }
+
+    fn new() -> Self {
+        Handler
+    }
+}
+
+extern "C" fn callback(_: *mut u8, _: usize) -> i32 {
+    0
+}
+
+fn identity<T>(value: T) -> T {
+    value
+}
+
+assert_fn_sig!(Handler::handle: fn(&Handler, &[u8]) -> Option<usize>);
+assert_fn_sig!(Handler::new: fn() -> Handler,);
+assert_fn_sig!(
+    callback: extern "C" fn(*mut u8, usize) -> i32,
+    identity::<u8>: fn(u8) -> u8,
+    identity: fn(&'static str) -> &'static str,
+    mem::drop::<Handler>: fn(Handler),
+);
+assert_fn_sig!(core::ptr::write::<u8>: unsafe fn(*mut u8, u8));
+assert_fn_sig!(identity: unsafe fn(u16) -> u16);
+assert_fn_sig!(Handler::handle: for<'a> fn(&'a Handler, &'static [u8]) -> Option<usize>);
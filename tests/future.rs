@@ -0,0 +1,50 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+use core::cell::Cell;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+// Like `core::future::Ready`, which requires Rust 1.48.
+struct Ready<T>(Option<T>);
+
+impl<T> Unpin for Ready<T> {}
+
+impl<T> Future for Ready<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, _: &mut Context) -> Poll<T> {
+        Poll::Ready(self.0.take().expect("polled after completion"))
+    }
+}
+
+fn ready<T>(value: T) -> Ready<T> {
+    Ready(Some(value))
+}
+
+fn fetch(id: u32, _: &str) -> impl Future<Output = u32> {
+    ready(id)
+}
+
+fn local(_: u8) -> Ready<Cell<u8>> {
+    ready(Cell::new(0))
+}
+
+struct Service;
+
+impl Service {
+    fn call<T>(&self, value: T) -> impl Future<Output = T> {
+        ready(value)
+    }
+}
+
+assert_future_impl!(fetch(_, _): Send + Sync + 'static);
+assert_future_impl!(fetch(1, "one"): Unpin & !(Send => Copy));
+assert_future_impl!(local(_): Send & !Sync);
+assert_future_impl!(local(0): Sync | Unpin);
+assert_future_impl!(Service::call::<&str>(_, _): (Send ^ Sync) => Unpin);
+assert_future_impl!(Service::call(&Service, Cell::new(1u8)): Send + !Sync);
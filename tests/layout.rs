@@ -0,0 +1,42 @@
+#![no_std]
+#![deny(unsafe_code)]
+// Braces in types are kept to check that they are not treated as placeholders.
+#![allow(unknown_lints, unused_braces)]
+
+#[macro_use]
+extern crate static_assertions;
+
+use core::mem::size_of;
+
+#[allow(dead_code)]
+#[repr(C, align(64))]
+struct Slot {
+    seq: u64,
+    data: [u8; 48],
+}
+
+const CACHE_LINE: usize = 64;
+
+assert_size!(Slot == 64);
+assert_size!(Slot == CACHE_LINE);
+assert_size!(Slot <= 2 * CACHE_LINE);
+assert_size!(Slot >= 1);
+assert_size!(Slot != 0);
+assert_size!([u8; 3] == 3);
+assert_size!(Option<&'static Slot> == size_of::<usize>());
+assert_size!(Result<(u8, u16), ()> >= 4);
+assert_size!(<u8 as core::ops::Add>::Output == 1);
+
+assert_align!(Slot == 64);
+assert_align!(Slot >= CACHE_LINE);
+assert_align!([u16; 4] <= 2);
+assert_align!(u8 != 2);
+
+assert_size_eq!(Slot, [u64; 8], [u8; 64]);
+assert_align_eq!(Slot, [Slot; 2]);
+assert_align_ne!(Slot, u8, u64);
+assert_align_lt!(u8, u16, u32, Slot);
+assert_align_le!(u8, i8, u16, u16);
+assert_align_gt!(Slot, u32, u16, u8);
+assert_align_ge!(Slot, Slot, u8);
+assert_niche!(&'static Slot, &'static [u8; { 2 + 2 }]);
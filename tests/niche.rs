@@ -0,0 +1,23 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+use core::num::NonZeroU8;
+use core::ptr::NonNull;
+
+#[allow(dead_code)]
+struct Handle(NonNull<u8>);
+
+#[allow(dead_code)]
+enum Level {
+    Low,
+    High,
+}
+
+assert_niche!(Handle);
+assert_niche!(&'static [u8], NonZeroU8, Level, bool, char,);
+assert_niche!(Handle => Result<Handle, ()>, bool => Option<Option<bool>>);
+assert_niche!(for<'a, T: ?Sized + 'a> &'a T, NonNull<T>);
+assert_niche!(for<T> NonNull<T> => Result<NonNull<T>, ()> where T: Copy);
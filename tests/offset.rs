@@ -0,0 +1,41 @@
+#![cfg(offset_of)]
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Header {
+    magic: [u8; 4],
+    len: u32,
+    inner: Inner,
+}
+
+#[allow(dead_code)]
+#[repr(C)]
+struct Inner(u16, (u8, u8));
+
+assert_offset_eq!(Header, magic, 0);
+assert_offset_eq!(Header, len, 4,);
+assert_offset_eq!(Header, inner.1 .1, 11);
+assert_offset_eq!(Inner, 1.0, 2);
+assert_offset_lt!(Header, len, 5);
+assert_offset_le!(Header, len, 4);
+assert_offset_gt!(Header, inner.0, 4);
+assert_offset_ge!(Header, inner, 8);
+
+mod m {
+    #[allow(dead_code)]
+    pub struct Private {
+        pub a: u8,
+    }
+
+    assert_offset_eq!(Private, a, 0);
+}
+
+#[test]
+fn test_offset_in_fn() {
+    assert_offset_eq!(Inner, 0, 0);
+}
@@ -0,0 +1,46 @@
+#![cfg(const_msg)]
+#![no_std]
+#![deny(unsafe_code)]
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+#[repr(C)]
+struct Header {
+    magic: [u8; 4],
+    version: u16,
+    flags: u16,
+    len: u64,
+}
+
+assert_no_padding!(Header: magic, version, flags, len);
+assert_no_padding!(Header: len, flags, version, magic,);
+
+struct Pair(u32, u32);
+
+assert_no_padding!(Pair: 1, 0);
+
+struct Borrowed<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+assert_no_padding!(Borrowed<'static>: bytes, pos);
+
+mod inner {
+    pub struct Empty {
+        pub zst: (),
+    }
+}
+
+assert_no_padding!(inner::Empty: zst);
+
+#[repr(C, packed)]
+struct Packed {
+    kind: u8,
+    len: u64,
+    id: u16,
+}
+
+assert_no_padding!(Packed: kind, len, id);
@@ -0,0 +1,47 @@
+#![cfg(feature = "proc")]
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+use std::cell::Cell;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+trait A {}
+trait B {}
+
+#[assert(impl(Send, Sync, Copy), not_impl(A, B), impl_one(Clone, A))]
+#[derive(Clone, Copy)]
+struct Foo {
+    value: i32,
+}
+
+#[assert(impl(Send), not_impl(Sync, Copy))]
+enum Bar<'a> {
+    A(&'a u8),
+    B(Cell<u8>),
+}
+
+#[assert(impl(Clone, Send), not_impl(Copy))]
+#[derive(Clone)]
+struct Generic<'a, T: Clone + Sync + 'a, U = u8>
+where
+    U: Send + Clone,
+{
+    value: &'a T,
+    marker: PhantomData<U>,
+}
+
+#[assert(impl(Sync), not_impl(Send), impl_one(Send, Sync))]
+struct Pointer<T: Sync, U: ?Sized = [u8]>(*const T, PhantomData<*const U>);
+
+unsafe impl<T: Sync, U: ?Sized> Sync for Pointer<T, U> {}
+
+// Holds since `Maybe<Rc<u8>>` is not `Send`, even though `Maybe<u8>` is.
+#[assert(not_impl(Send, Sync), impl_one(Send, Clone))]
+#[derive(Clone)]
+struct Maybe<T: Clone>(T);
+
+assert_impl_all!(Maybe<u8>: Send, Sync);
+assert_impl_not_any!(Maybe<Rc<u8>>: Send, Sync);
@@ -0,0 +1,28 @@
+#![cfg(all(feature = "proc", const_msg))]
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+#[assert(no_padding, size == 16)]
+#[repr(C)]
+struct Header {
+    magic: [u8; 4],
+    version: u16,
+    flags: u16,
+    len: u64,
+}
+
+#[assert(no_padding)]
+struct Pair(u32, u32);
+
+#[assert(no_padding)]
+struct Unit;
+
+#[assert(no_padding, size == 11)]
+#[repr(C, packed)]
+struct Packed {
+    kind: u8,
+    len: u64,
+    id: u16,
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "proc")]
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+#[assert(repr(C))]
+#[repr(C)]
+struct Header {
+    magic: u32,
+    len: u32,
+}
+
+#[repr(C, align(8))]
+#[assert(repr(C), repr(align(8)))]
+struct Aligned(u32);
+
+#[assert(repr(transparent), size == 4)]
+#[repr(transparent)]
+struct Handle(u32, std::marker::PhantomData<*const ()>);
+
+#[assert(repr(u8))]
+#[repr(u8)]
+enum Kind {
+    A,
+    B = 4,
+}
+
+#[assert(repr(C, i32))]
+#[repr(C)]
+#[repr(i32)]
+enum Tag {
+    X(u8),
+    Y { a: u16 },
+}
+
+#[assert(repr(packed))]
+#[repr(C, packed)]
+struct Packed {
+    a: u8,
+    b: u32,
+}
+
+#[assert(repr(packed(2)))]
+#[repr(C, packed(2))]
+struct Packed2 {
+    a: u8,
+    b: u32,
+}
+
+#[assert(repr(C))]
+#[repr(C)]
+struct Generic<T>(T);
@@ -1,4 +1,5 @@
 #![cfg(feature = "proc")]
+#![allow(dead_code)]
 
 #[macro_use]
 extern crate static_assertions;
@@ -7,3 +8,9 @@ extern crate static_assertions;
 struct Foo {
     value: i32,
 }
+
+#[assert(size <= 8, align != 8)]
+enum Bar {
+    A(u16),
+    B(u32),
+}
@@ -58,6 +58,7 @@ assert_impl!(Test: !(Copy) & !(!Clone));
 assert_impl!(Test: !(Copy & Clone));
 assert_impl!(str: !Copy & !Clone);
 
+#[allow(dead_code)]
 #[derive(Clone)]
 struct Box<T>(T);
 
@@ -69,3 +70,64 @@ assert_impl!(for(T) PhantomData<T>: Clone);
 assert_impl!(for(T: Copy) T: Clone);
 assert_impl!(for(T: ?Sized) T: Clone | !Clone);
 assert_impl!(for('a, T: 'a) &'a mut T: !Copy);
+
+struct Holder<T>(T);
+
+impl<T: Clone> Clone for Holder<T> {
+    fn clone(&self) -> Self {
+        Holder(self.0.clone())
+    }
+}
+
+assert_impl_all!(for<T: Clone> Holder<T>: Clone);
+assert_impl_all!(for<T> Holder<T>: Clone, Send where T: Clone + Send,);
+assert_impl_all!(for<T: Into<Option<u8>>> Holder<T>: Sized);
+assert_impl_all!(for<'a> fn(&'a u8) -> &'a u8: Copy);
+assert_impl_any!(for<T> Holder<T>: Clone, Sized);
+assert_impl_not_all!(for<T> Holder<T>: Clone);
+assert_impl_not_any!(for<T: Copy> Holder<T>: Copy, Into<u8>);
+assert_impl_one!(for<T: Clone> Holder<T>: Clone, Copy);
+assert_impl!(for<T: Clone> Holder<T>: Clone & !Copy);
+assert_impl!(for<T> Holder<T>: Clone where T: Copy);
+
+assert_impl!(u8: (Iterator & Copy) | Clone);
+assert_impl!(u8: Copy | (Sync & !Send) | Iterator);
+assert_impl!(u8: (Into<u16> & AsRef<[u8]>) ^ Into<Option<u8>>);
+assert_impl!(u8: (Copy ^ Clone) ^ Send);
+assert_impl!(str: Copy ^ Send);
+assert_impl!(str: Copy => Clone);
+assert_impl!(str: Copy => Send => Sized);
+assert_impl!(str: !(Send => Copy));
+assert_impl!(u8: Fn(&str) -> bool => Copy);
+assert_impl!(&'static str: AsRef<&'static str> | Into<Option<&'static str>>);
+assert_impl!(for<T: Into<u8>> Holder<T>: Copy ^ !Clone);
+assert_impl!(u8: Copy + Send + 'static);
+assert_impl!(str: (Send + Sync) ^ Sized);
+assert_impl!(for<T: 'static> Holder<T>: 'static + Sized);
+
+// Mixed operators are parsed right-associatively, as in 1.1.0.
+assert_impl!(u8: !(Iterator & Copy | Clone));
+assert_impl!(u8: Copy | Iterator & Send);
+assert_impl!(u8: Copy + Send | Iterator);
+assert_impl!(str: !(Sized ^ Send => Copy));
+const_assert!(does_impl!(u8: Iterator | Copy & Send));
+
+// Long lists must not exceed the recursion limit.
+assert_impl_all!(
+    u8: Copy, Clone, Send, Sync, Sized, Default, core::fmt::Debug, core::fmt::Display,
+    core::fmt::Binary, core::fmt::Octal, core::fmt::LowerHex, core::fmt::UpperHex,
+    core::hash::Hash, PartialEq<u8>, Eq, PartialOrd<u8>, Ord, From<u8>, From<bool>, Into<u16>,
+    Into<u32>, Into<u64>, Into<u128>, Into<i16>, Into<i32>, Into<i64>, Into<i128>,
+    Into<f32>, Into<f64>, core::str::FromStr, core::ops::Add<u8>, core::ops::Sub<u8>,
+    core::ops::Mul<u8>, core::ops::Div<u8>, core::ops::Rem<u8>, core::ops::BitAnd<u8>, core::ops::BitOr<u8>,
+    core::ops::BitXor<u8>, core::ops::Not, core::ops::Shl<u8>, core::ops::Shr<u8>,
+);
+assert_impl!(
+    u8: Copy & Clone & Send & Sync & Sized & Default & Eq & Ord & core::hash::Hash
+        & core::fmt::Debug & core::fmt::Display & core::fmt::Binary & core::fmt::Octal
+        & core::ops::Add<u8> & core::ops::Sub<u8> & core::ops::Mul<u8> & core::ops::Div<u8>
+        & core::ops::Rem<u8> & core::ops::Not & core::ops::BitAnd<u8> & core::ops::BitOr<u8>
+        & core::ops::BitXor<u8> & Into<u16> & Into<u32> & Into<u64> & Into<i16>
+        & Into<i32> & Into<i64> & Into<f32> & Into<f64> & From<bool> & From<u8>
+        & core::str::FromStr
+);
@@ -0,0 +1,28 @@
+#![no_std]
+#![deny(unsafe_code)]
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+use core::marker::PhantomData;
+use core::num::NonZeroU16;
+
+#[repr(transparent)]
+struct Port(NonZeroU16);
+
+#[repr(transparent)]
+struct Tagged<T>(u32, PhantomData<T>);
+
+#[repr(transparent)]
+struct Bytes([u8; 3]);
+
+struct Ref<'a>(&'a [u64]);
+
+assert_transparent!(Port, NonZeroU16, niche);
+assert_transparent!(Port, u16);
+assert_transparent!(Tagged<fn() -> u8>, u32, niche);
+assert_transparent!(Bytes, [u8; 3]);
+assert_transparent!(Bytes, (u8, u8, u8), niche);
+assert_transparent!(Ref<'static>, &'static [u64], niche,);
+assert_transparent!([Port; 2], [u16; 2],);
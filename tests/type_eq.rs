@@ -5,6 +5,8 @@
 extern crate static_assertions;
 
 assert_type_eq_all!([u8], [u8]);
+assert_type_eq_all!(for<T: Iterator> <T as IntoIterator>::Item, T::Item);
+assert_type_eq_all!(for<'a, T: 'a> <&'a [T] as IntoIterator>::Item, &'a T,);
 
 #[allow(dead_code)]
 type X = u8;
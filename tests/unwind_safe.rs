@@ -0,0 +1,25 @@
+#![cfg(core_unwind_safe)]
+#![no_std]
+#![deny(unsafe_code)]
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+use core::cell::Cell;
+use core::marker::PhantomData;
+
+struct Plain {
+    a: u32,
+    b: &'static str,
+}
+
+struct Local {
+    a: u32,
+    _marker: PhantomData<*const u8>,
+}
+
+assert_unwind_safe!(Plain, Local);
+assert_unwind_safe!(Cell<u8>, [u8; 4],);
+
+assert_not_unwind_safe!(&'static Cell<u8>, &'static mut u8);
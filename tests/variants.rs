@@ -0,0 +1,30 @@
+#![no_std]
+#![deny(unsafe_code)]
+
+#[macro_use]
+extern crate static_assertions;
+
+#[allow(dead_code)]
+enum Op {
+    Nop,
+    Push(u8),
+    Jump { offset: i16 },
+}
+
+assert_variants!(Op: Nop);
+assert_variants!(Op: Push, Jump,);
+assert_variants!(Op: Jump, Jump);
+assert_variants_exact!(Op: Nop, Push, Jump);
+assert_variants_exact!(Op: Jump, Push, Nop, Jump);
+
+mod m {
+    #[allow(dead_code)]
+    pub enum Either<L, R> {
+        Left(L),
+        Right(R),
+    }
+}
+
+assert_variants!(m::Either<u8, u16>: Left);
+assert_variants_exact!(m::Either<(), ()>: Left, Right);
+assert_variants_exact!(core::cmp::Ordering: Less, Equal, Greater);